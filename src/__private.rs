@@ -0,0 +1,274 @@
+//! Items used by the expansion of the exported macros.
+//!
+//! These are not part of the public API and can change at any time.
+
+/// Error of [`parse_u128`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The radix is not in the range of `2..=36`.
+    InvalidRadix,
+    /// The string is empty.
+    Empty,
+    /// The string contains a character that is not a digit of the radix.
+    InvalidDigit,
+    /// The number does not fit into [`prim@u128`].
+    Overflow,
+}
+
+/// Parses an unsigned integer of the given radix from a string.
+///
+/// The string may start with a `+` sign and `_` separators are skipped after
+/// the first digit like in integer literals. Unlike [`u128::from_str_radix`],
+/// this function can be called in constant contexts.
+pub const fn parse_u128(src: &str, radix: u32) -> Result<u128, ParseError> {
+    if radix < 2 || radix > 36 {
+        return Err(ParseError::InvalidRadix);
+    }
+    let bytes = src.as_bytes();
+    if bytes.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let mut index = if bytes[0] == b'+' { 1 } else { 0 };
+    if index == bytes.len() {
+        return Err(ParseError::InvalidDigit);
+    }
+    let mut value: u128 = 0;
+    let mut has_digit = false;
+    while index < bytes.len() {
+        if bytes[index] == b'_' {
+            if !has_digit {
+                return Err(ParseError::InvalidDigit);
+            }
+            index += 1;
+            continue;
+        }
+        let digit = match bytes[index] {
+            byte @ b'0'..=b'9' => (byte - b'0') as u32,
+            byte @ b'a'..=b'z' => (byte - b'a') as u32 + 10,
+            byte @ b'A'..=b'Z' => (byte - b'A') as u32 + 10,
+            _ => return Err(ParseError::InvalidDigit),
+        };
+        if digit >= radix {
+            return Err(ParseError::InvalidDigit);
+        }
+        value = match value.checked_mul(radix as u128) {
+            Some(value) => value,
+            None => return Err(ParseError::Overflow),
+        };
+        value = match value.checked_add(digit as u128) {
+            Some(value) => value,
+            None => return Err(ParseError::Overflow),
+        };
+        has_digit = true;
+        index += 1;
+    }
+    Ok(value)
+}
+
+/// Fails to compile unless `Source` can be converted to `Target` without loss
+/// (i.e. `Source` implements [`Into<Target>`]).
+///
+/// The value is only used for inferring `Source`, so the conversion is never
+/// performed and the function can be called in constant contexts.
+pub const fn assert_lossless<Source: Into<Target>, Target>(_: &Source) {}
+
+/// Returns `value` after unifying its type with the type of the second
+/// argument.
+///
+/// It is used for inferring the type of an `as _` cast from another value in
+/// constant contexts, where the type cannot be named.
+pub const fn same_type<T: Copy>(value: T, _: T) -> T {
+    value
+}
+
+/// Length of the buffer of [`format_u128`] and [`format_i128`], which is the
+/// length of `i128::MIN` in decimal.
+pub const FORMAT_LEN: usize = 40;
+
+/// Formats an unsigned integer in decimal into the end of a buffer.
+///
+/// Returns the buffer and the index of the first byte of the formatted number,
+/// so the number is the tail of the buffer starting at the index.
+pub const fn format_u128(mut value: u128) -> ([u8; FORMAT_LEN], usize) {
+    let mut bytes = [0; FORMAT_LEN];
+    let mut index = FORMAT_LEN;
+    loop {
+        index -= 1;
+        bytes[index] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break (bytes, index);
+        }
+    }
+}
+
+/// Formats a signed integer in decimal into the end of a buffer like
+/// [`format_u128`].
+pub const fn format_i128(value: i128) -> ([u8; FORMAT_LEN], usize) {
+    let (mut bytes, mut index) = format_u128(value.unsigned_abs());
+    if value < 0 {
+        index -= 1;
+        bytes[index] = b'-';
+    }
+    (bytes, index)
+}
+
+/// Returns whether the decimal representation of an unsigned integer contains
+/// a `0` digit.
+pub const fn has_zero_digit(mut value: u128) -> bool {
+    loop {
+        if value % 10 == 0 {
+            break true;
+        }
+        value /= 10;
+        if value == 0 {
+            break false;
+        }
+    }
+}
+
+/// Returns whether an integer literal ends with a type suffix (e.g. `5u8`).
+///
+/// The suffixes start with `i` or `u`, which are not hexadecimal digits, so
+/// the digits of the literal cannot be mistaken for a suffix.
+pub const fn has_int_suffix(literal: &str) -> bool {
+    const SUFFIXES: [&str; 12] = [
+        "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64",
+        "u128", "usize",
+    ];
+    let bytes = literal.as_bytes();
+    let mut index = 0;
+    while index < SUFFIXES.len() {
+        let suffix = SUFFIXES[index].as_bytes();
+        if bytes.len() > suffix.len() {
+            let offset = bytes.len() - suffix.len();
+            let mut matched = 0;
+            while matched < suffix.len()
+                && bytes[offset + matched] == suffix[matched]
+            {
+                matched += 1;
+            }
+            if matched == suffix.len() {
+                return true;
+            }
+        }
+        index += 1;
+    }
+    false
+}
+
+/// Maximum length of the message of [`panic_at_index`].
+const INDEX_MESSAGE_LEN: usize = 128;
+
+/// Panics with a message that is followed by an index.
+///
+/// The message can be at most `INDEX_MESSAGE_LEN` bytes long including the
+/// formatted index.
+pub const fn panic_at_index(message: &str, index: usize) -> ! {
+    let (digits, mut start) = format_u128(index as u128);
+    let mut buffer = [0; INDEX_MESSAGE_LEN];
+    let mut len = 0;
+    while len < message.len() {
+        buffer[len] = message.as_bytes()[len];
+        len += 1;
+    }
+    while start < FORMAT_LEN {
+        buffer[len] = digits[start];
+        len += 1;
+        start += 1;
+    }
+    match core::str::from_utf8(buffer.split_at(len).0) {
+        Ok(message) => panic!("{}", message),
+        // the message is a valid string followed by ASCII digits
+        Err(_) => unreachable!(),
+    }
+}
+
+/// Generates a function that converts an array of the specified integer type
+/// to an array of non-zero values.
+macro_rules! gen_non_zero_array_fn {
+    ($fn_name:ident, $int_type:ident) => {
+        #[doc = concat!("Converts an array of [`prim@", stringify!($int_type), "`] to an array of non-zero values.")]
+        ///
+        /// Panics with the message followed by the index of the first zero
+        /// element, therefore it fails to compile if it is called in a constant
+        /// context with a zero element.
+        pub const fn $fn_name<const N: usize>(
+            values: &[$int_type; N],
+            message: &str,
+        ) -> [core::num::NonZero<$int_type>; N] {
+            let mut non_zero_values = [core::num::NonZero::<$int_type>::MIN; N];
+            let mut index = 0;
+            while index < N {
+                match core::num::NonZero::new(values[index]) {
+                    Some(non_zero) => non_zero_values[index] = non_zero,
+                    None => panic_at_index(message, index),
+                }
+                index += 1;
+            }
+            non_zero_values
+        }
+    };
+}
+
+gen_non_zero_array_fn!(non_zero_array_i8, i8);
+gen_non_zero_array_fn!(non_zero_array_i16, i16);
+gen_non_zero_array_fn!(non_zero_array_i32, i32);
+gen_non_zero_array_fn!(non_zero_array_i64, i64);
+gen_non_zero_array_fn!(non_zero_array_i128, i128);
+gen_non_zero_array_fn!(non_zero_array_isize, isize);
+gen_non_zero_array_fn!(non_zero_array_u8, u8);
+gen_non_zero_array_fn!(non_zero_array_u16, u16);
+gen_non_zero_array_fn!(non_zero_array_u32, u32);
+gen_non_zero_array_fn!(non_zero_array_u64, u64);
+gen_non_zero_array_fn!(non_zero_array_u128, u128);
+gen_non_zero_array_fn!(non_zero_array_usize, usize);
+
+/// Returns the content of a string literal token, or the token itself if it
+/// is not a string literal.
+pub const fn unquote(token: &str) -> &str {
+    let bytes = token.as_bytes();
+    let len = bytes.len();
+    if len < 2 || bytes[0] != b'"' || bytes[len - 1] != b'"' {
+        return token;
+    }
+    let (content, _) = bytes.split_at(len - 1);
+    let (_, content) = content.split_at(1);
+    match core::str::from_utf8(content) {
+        Ok(content) => content,
+        // only the ASCII quotes are removed, so the content is valid UTF-8
+        Err(_) => unreachable!(),
+    }
+}
+
+/// Generates a function that computes the greatest common divisor of two
+/// non-zero values of the specified unsigned integer type.
+macro_rules! gen_gcd_fn {
+    ($fn_name:ident, $int_type:ident) => {
+        #[doc = concat!("Computes the greatest common divisor of two non-zero [`prim@", stringify!($int_type), "`] values.")]
+        ///
+        /// The Euclidean algorithm finishes with the last non-zero remainder,
+        /// so the result never has to be checked again.
+        pub const fn $fn_name(
+            mut a: core::num::NonZero<$int_type>,
+            mut b: core::num::NonZero<$int_type>,
+        ) -> core::num::NonZero<$int_type> {
+            loop {
+                match core::num::NonZero::new(a.get() % b.get()) {
+                    Some(rem) => {
+                        a = b;
+                        b = rem;
+                    }
+                    None => break b,
+                }
+            }
+        }
+    };
+}
+
+gen_gcd_fn!(gcd_u8, u8);
+gen_gcd_fn!(gcd_u16, u16);
+gen_gcd_fn!(gcd_u32, u32);
+gen_gcd_fn!(gcd_u64, u64);
+gen_gcd_fn!(gcd_u128, u128);
+gen_gcd_fn!(gcd_usize, usize);
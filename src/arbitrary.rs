@@ -0,0 +1,86 @@
+//! Macros that help creating [`NonZero`][`core::num::NonZero`] values with
+//! `arbitrary`.
+
+/// Generates an `arbitrary` non-zero macro for the specified integer type.
+macro_rules! gen_nz_arbitrary_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] from an `arbitrary::Unstructured`")]
+        /// that never fails.
+        ///
+        #[doc = concat!("The first argument is the `Unstructured` from which a [`prim@", stringify!($int_type), "`] is taken")]
+        /// and the second one is a literal, a constant value or expression that
+        /// is used as a fallback value if the taken value is zero or the data
+        /// is exhausted. The fallback value is checked at compile time, so if it
+        /// evaluates to zero, a [`panic`] will occur at compile time. This way
+        /// fuzzers do not have to reject inputs that would result in zero.
+        ///
+        /// Available on crate feature `arbitrary` only.
+        ///
+        /// # Examples
+        ///
+        /// #### From `Unstructured`
+        /// ```rust
+        /// # struct Unstructured<'a>(&'a [u8]);
+        /// # impl<'a> Unstructured<'a> {
+        /// #     fn new(data: &'a [u8]) -> Self { Self(data) }
+        /// #     fn arbitrary<T: TryFrom<u8>>(&mut self) -> Result<T, ()> {
+        /// #         let (&first, rest) = self.0.split_first().ok_or(())?;
+        /// #         self.0 = rest;
+        /// #         T::try_from(first).map_err(|_| ())
+        /// #     }
+        /// # }
+        /// let mut u = Unstructured::new(&[7, 0]);
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(u, 1);")]
+        #[doc = concat!(" let zero = nz::", stringify!($macro_name), "!(u, 1);")]
+        #[doc = concat!(" let exhausted = nz::", stringify!($macro_name), "!(u, 1);")]
+        /// # assert_eq!(7, nz.get());
+        /// # assert_eq!(1, zero.get());
+        /// # assert_eq!(1, exhausted.get());
+        /// ```
+        ///
+        /// #### Zero fallback value fails to compile
+        /// ```rust, compile_fail
+        /// # struct Unstructured;
+        /// # impl Unstructured {
+        /// #     fn arbitrary<T>(&mut self) -> Result<T, ()> { Err(()) }
+        /// # }
+        /// # let mut u = Unstructured;
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(u, 0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($unstructured:expr, $fallback_expr:expr) => {
+                match $unstructured.arbitrary::<$int_type>() {
+                    Ok(int_value) => match core::num::NonZero::<$int_type>::new(int_value) {
+                        Some(non_zero) => non_zero,
+                        None => $crate::__nz_operand!($macro_name, $int_type, $fallback_expr),
+                    },
+                    Err(_) => $crate::__nz_operand!($macro_name, $int_type, $fallback_expr),
+                }
+            };
+        }
+    };
+}
+
+/// Generates an `arbitrary` non-zero macro from each macro name and integer
+/// type pair.
+macro_rules! gen_nz_arbitrary_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_arbitrary_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_arbitrary_macros!(
+    arbitrary_i8: i8,
+    arbitrary_i16: i16,
+    arbitrary_i32: i32,
+    arbitrary_i64: i64,
+    arbitrary_i128: i128,
+    arbitrary_isize: isize,
+    arbitrary_u8: u8,
+    arbitrary_u16: u16,
+    arbitrary_u32: u32,
+    arbitrary_u64: u64,
+    arbitrary_u128: u128,
+    arbitrary_usize: usize
+);
@@ -0,0 +1,1353 @@
+//! Macros that perform arithmetic operations resulting in a
+//! [`NonZero`][`core::num::NonZero`] at compile time.
+
+/// Generates a non-zero greatest common divisor macro for the specified
+/// unsigned integer type.
+macro_rules! gen_nz_gcd_macro {
+    ($macro_name:ident, $int_type:ident, $gcd_fn:ident) => {
+        #[doc = concat!("Computes the greatest common divisor of two literals, constant values or")]
+        #[doc = concat!("expressions that evaluate to non-zero [`prim@", stringify!($int_type), "`] as a")]
+        #[doc = concat!("[`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// The greatest common divisor of two non-zero values is always
+        /// non-zero, therefore only the arguments are checked. If any of the
+        /// arguments evaluates to zero, a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literals
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(12, 18);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(7, 5);")]
+        /// # assert_eq!(6, NZ.get());
+        /// # assert_eq!(1, nz.get());
+        /// ```
+        ///
+        /// #### From constant expressions
+        /// ```rust
+        #[doc = concat!(" const WIDTH: ", stringify!($int_type), " = 64;")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(WIDTH, WIDTH / 2 + 8);")]
+        /// # assert_eq!(8, nz.get());
+        /// ```
+        ///
+        /// #### Zero argument fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(12, 0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($a_expr:expr, $b_expr:expr) => {
+                const {
+                    let a = $crate::__nz_operand!($macro_name, $int_type, $a_expr);
+                    let b = $crate::__nz_operand!($macro_name, $int_type, $b_expr);
+                    $crate::__private::$gcd_fn(a, b)
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero greatest common divisor macro from each macro name,
+/// unsigned integer type and greatest common divisor function triple.
+macro_rules! gen_nz_gcd_macros {
+    ($($macro_name:ident: $int_type:ident => $gcd_fn:ident), *) => {
+        $(gen_nz_gcd_macro!($macro_name, $int_type, $gcd_fn);)*
+    };
+}
+
+gen_nz_gcd_macros!(
+    gcd_u8: u8 => gcd_u8,
+    gcd_u16: u16 => gcd_u16,
+    gcd_u32: u32 => gcd_u32,
+    gcd_u64: u64 => gcd_u64,
+    gcd_u128: u128 => gcd_u128,
+    gcd_usize: usize => gcd_usize
+);
+
+/// Generates a non-zero least common multiple macro for the specified unsigned
+/// integer type.
+macro_rules! gen_nz_lcm_macro {
+    ($macro_name:ident, $int_type:ident, $gcd_fn:ident) => {
+        #[doc = concat!("Computes the least common multiple of two literals, constant values or")]
+        #[doc = concat!("expressions that evaluate to non-zero [`prim@", stringify!($int_type), "`] as a")]
+        #[doc = concat!("[`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// The least common multiple of two non-zero values is always non-zero,
+        /// therefore only the arguments are checked. It is computed as
+        /// `a / gcd(a, b) * b`, so the multiplication only overflows if the
+        /// result does. If any of the arguments evaluates to zero or the
+        /// multiplication overflows, a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literals
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(4, 6);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX, ", stringify!($int_type), "::MAX);")]
+        /// # assert_eq!(12, NZ.get());
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::MAX, nz.get());")]
+        /// ```
+        ///
+        /// #### Zero argument fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(4, 0);")]
+        /// ```
+        ///
+        /// #### Overflowing multiplication fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX, 2);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($a_expr:expr, $b_expr:expr) => {
+                const {
+                    let a = $crate::__nz_operand!($macro_name, $int_type, $a_expr);
+                    let b = $crate::__nz_operand!($macro_name, $int_type, $b_expr);
+                    let gcd = $crate::__private::$gcd_fn(a, b);
+                    let quotient = match core::num::NonZero::<$int_type>::new(a.get() / gcd.get()) {
+                        Some(quotient) => quotient,
+                        // the divisor is not greater than the dividend
+                        None => unreachable!(),
+                    };
+                    match quotient.checked_mul(b) {
+                        Some(lcm) => lcm,
+                        None => panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: multiplication overflowed"
+                        )),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero least common multiple macro from each macro name,
+/// unsigned integer type and greatest common divisor function triple.
+macro_rules! gen_nz_lcm_macros {
+    ($($macro_name:ident: $int_type:ident => $gcd_fn:ident), *) => {
+        $(gen_nz_lcm_macro!($macro_name, $int_type, $gcd_fn);)*
+    };
+}
+
+gen_nz_lcm_macros!(
+    lcm_u8: u8 => gcd_u8,
+    lcm_u16: u16 => gcd_u16,
+    lcm_u32: u32 => gcd_u32,
+    lcm_u64: u64 => gcd_u64,
+    lcm_u128: u128 => gcd_u128,
+    lcm_usize: usize => gcd_usize
+);
+
+/// Generates a non-zero multiplication macro for the specified integer type.
+macro_rules! gen_nz_mul_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Multiplies two literals, constant values or expressions that evaluate to")]
+        #[doc = concat!("non-zero [`prim@", stringify!($int_type), "`] resulting in a")]
+        #[doc = concat!("[`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// The product of two non-zero values is always non-zero, therefore only
+        /// the arguments are checked. If any of the arguments evaluates to zero
+        /// or the multiplication overflows, a [`panic`] will occur at compile
+        /// time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literals
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(3, 5);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(NZ.get(), 2);")]
+        /// # assert_eq!(15, NZ.get());
+        /// # assert_eq!(30, nz.get());
+        /// ```
+        ///
+        /// #### Zero argument fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0, 5);")]
+        /// ```
+        ///
+        /// #### Overflowing multiplication fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX, 2);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($a_expr:expr, $b_expr:expr) => {
+                const {
+                    let a = $crate::__nz_operand!($macro_name, $int_type, $a_expr);
+                    let b = $crate::__nz_operand!($macro_name, $int_type, $b_expr);
+                    match a.checked_mul(b) {
+                        Some(product) => product,
+                        None => panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: multiplication overflowed"
+                        )),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero multiplication macro from each macro name and integer
+/// type pair.
+macro_rules! gen_nz_mul_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_mul_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_mul_macros!(
+    mul_i8: i8,
+    mul_i16: i16,
+    mul_i32: i32,
+    mul_i64: i64,
+    mul_i128: i128,
+    mul_isize: isize,
+    mul_u8: u8,
+    mul_u16: u16,
+    mul_u32: u32,
+    mul_u64: u64,
+    mul_u128: u128,
+    mul_usize: usize
+);
+
+/// Generates a non-zero exponentiation macro for the specified integer type.
+macro_rules! gen_nz_pow_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Raises a literal, constant value or expression that evaluates to non-zero")]
+        #[doc = concat!("[`prim@", stringify!($int_type), "`] to the power of a constant [`prim@u32`] exponent resulting")]
+        #[doc = concat!("in a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// Any power of a non-zero value is non-zero, therefore only the base is
+        /// checked. If the base evaluates to zero or the exponentiation
+        /// overflows, a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literals
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(2, 6);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(NZ.get(), 0);")]
+        /// # assert_eq!(64, NZ.get());
+        /// # assert_eq!(1, nz.get());
+        /// ```
+        ///
+        /// #### Zero base fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0, 2);")]
+        /// ```
+        ///
+        /// #### Overflowing exponentiation fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(2, ", stringify!($int_type), "::BITS);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($base_expr:expr, $exp_expr:expr) => {
+                const {
+                    let base = $crate::__nz_operand!($macro_name, $int_type, $base_expr);
+                    let exp: u32 = $exp_expr;
+                    match base.checked_pow(exp) {
+                        Some(power) => power,
+                        None => panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: exponentiation overflowed"
+                        )),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero exponentiation macro from each macro name and integer
+/// type pair.
+macro_rules! gen_nz_pow_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_pow_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_pow_macros!(
+    pow_i8: i8,
+    pow_i16: i16,
+    pow_i32: i32,
+    pow_i64: i64,
+    pow_i128: i128,
+    pow_isize: isize,
+    pow_u8: u8,
+    pow_u16: u16,
+    pow_u32: u32,
+    pow_u64: u64,
+    pow_u128: u128,
+    pow_usize: usize
+);
+
+/// Generates a non-zero absolute value macro for the specified signed integer
+/// type.
+macro_rules! gen_nz_abs_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Computes the absolute value of a literal, constant value or expression that")]
+        #[doc = concat!("evaluates to non-zero [`prim@", stringify!($int_type), "`] as a")]
+        #[doc = concat!("[`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// The absolute value of a non-zero value is always non-zero, therefore
+        /// only the argument is checked. If the argument evaluates to zero or
+        #[doc = concat!("[`", stringify!($int_type), "::MIN`], whose absolute value overflows, a [`panic`] will occur at")]
+        /// compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literal
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(-27);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX);")]
+        /// # assert_eq!(27, NZ.get());
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::MAX, nz.get());")]
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        ///
+        /// #### Overflowing absolute value fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MIN);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {
+                const {
+                    let value = $crate::__nz_operand!($macro_name, $int_type, $int_expr);
+                    match value.checked_abs() {
+                        Some(abs) => abs,
+                        None => panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: absolute value overflowed"
+                        )),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero absolute value macro from each macro name and signed
+/// integer type pair.
+macro_rules! gen_nz_abs_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_abs_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_abs_macros!(
+    abs_i8: i8,
+    abs_i16: i16,
+    abs_i32: i32,
+    abs_i64: i64,
+    abs_i128: i128,
+    abs_isize: isize
+);
+
+/// Generates a non-zero sign macro for the specified signed integer type.
+macro_rules! gen_nz_signum_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Computes the sign of a literal, constant value or expression that evaluates to")]
+        #[doc = concat!("non-zero [`prim@", stringify!($int_type), "`] as a")]
+        #[doc = concat!("[`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// The sign of a non-zero value is either `-1` or `1`, which is always
+        /// non-zero, therefore only the argument is checked. If the argument
+        /// evaluates to zero, a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literals
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(-27);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX);")]
+        /// # assert_eq!(-1, NZ.get());
+        /// # assert_eq!(1, nz.get());
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {
+                const {
+                    let value = $crate::__nz_operand!($macro_name, $int_type, $int_expr);
+                    match core::num::NonZero::<$int_type>::new(value.get().signum()) {
+                        Some(signum) => signum,
+                        // the sign of a non-zero value is either -1 or 1
+                        None => unreachable!(),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero sign macro from each macro name and signed integer
+/// type pair.
+macro_rules! gen_nz_signum_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_signum_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_signum_macros!(
+    signum_i8: i8,
+    signum_i16: i16,
+    signum_i32: i32,
+    signum_i64: i64,
+    signum_i128: i128,
+    signum_isize: isize
+);
+
+/// Generates a non-zero unsigned absolute value macro for the specified signed
+/// integer type.
+macro_rules! gen_nz_unsigned_abs_macro {
+    ($macro_name:ident, $int_type:ident, $uint_type:ident) => {
+        #[doc = concat!("Computes the absolute value of a literal, constant value or expression that")]
+        #[doc = concat!("evaluates to non-zero [`prim@", stringify!($int_type), "`] as a")]
+        #[doc = concat!("[`NonZero<", stringify!($uint_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// The absolute value of a non-zero value is always non-zero, therefore
+        #[doc = concat!("only the argument is checked. Unlike [`nz::abs_", stringify!($int_type), "!`][`crate::abs_", stringify!($int_type), "`], the full range")]
+        #[doc = concat!("is accepted including [`", stringify!($int_type), "::MIN`]. If the argument evaluates to zero,")]
+        /// a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literal
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($uint_type), "> = nz::", stringify!($macro_name), "!(-27);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MIN);")]
+        /// # assert_eq!(27, NZ.get());
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::MIN.unsigned_abs(), nz.get());")]
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {
+                const {
+                    $crate::__nz_operand!($macro_name, $int_type, $int_expr).unsigned_abs()
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero unsigned absolute value macro from each macro name,
+/// signed and unsigned integer type triple.
+macro_rules! gen_nz_unsigned_abs_macros {
+    ($($macro_name:ident: $int_type:ident -> $uint_type:ident), *) => {
+        $(gen_nz_unsigned_abs_macro!($macro_name, $int_type, $uint_type);)*
+    };
+}
+
+gen_nz_unsigned_abs_macros!(
+    unsigned_abs_i8: i8 -> u8,
+    unsigned_abs_i16: i16 -> u16,
+    unsigned_abs_i32: i32 -> u32,
+    unsigned_abs_i64: i64 -> u64,
+    unsigned_abs_i128: i128 -> u128,
+    unsigned_abs_isize: isize -> usize
+);
+
+/// Generates a saturating non-zero arithmetic macro for the specified integer
+/// type.
+macro_rules! gen_nz_saturating_macro {
+    (
+        $macro_name:ident, $int_type:ident, $op_name:literal,
+        |$a:ident, $b:ident| $op:expr,
+        ($example_a:expr, $example_b:expr) -> $example:expr
+    ) => {
+        #[doc = concat!("Computes the saturating ", $op_name, " of two literals, constant values or")]
+        #[doc = concat!("expressions that evaluate to non-zero [`prim@", stringify!($int_type), "`] as a")]
+        #[doc = concat!("[`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// The result saturates at the numeric bounds instead of overflowing,
+        /// which are non-zero, therefore only the arguments are checked. If any
+        /// of the arguments evaluates to zero, a [`panic`] will occur at compile
+        /// time.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant expressions
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(", $example_a, ", ", $example_b, ");")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX, 2);")]
+        #[doc = concat!(" # assert_eq!(", $example, ", NZ.get());")]
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::MAX, nz.get());")]
+        /// ```
+        ///
+        /// #### Zero argument fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0, 2);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($a_expr:expr, $b_expr:expr) => {
+                const {
+                    let $a = $crate::__nz_operand!($macro_name, $int_type, $a_expr);
+                    let $b = $crate::__nz_operand!($macro_name, $int_type, $b_expr);
+                    $op
+                }
+            };
+        }
+    };
+}
+
+/// Generates a saturating non-zero addition macro from each macro name and
+/// unsigned integer type pair.
+macro_rules! gen_nz_saturating_add_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(
+            gen_nz_saturating_macro!(
+                $macro_name, $int_type, "addition",
+                |a, b| a.saturating_add(b.get()),
+                ("3", "5") -> "8"
+            );
+        )*
+    };
+}
+
+gen_nz_saturating_add_macros!(
+    saturating_add_u8: u8,
+    saturating_add_u16: u16,
+    saturating_add_u32: u32,
+    saturating_add_u64: u64,
+    saturating_add_u128: u128,
+    saturating_add_usize: usize
+);
+
+/// Generates a saturating non-zero multiplication macro from each macro name
+/// and integer type pair.
+macro_rules! gen_nz_saturating_mul_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(
+            gen_nz_saturating_macro!(
+                $macro_name, $int_type, "multiplication",
+                |a, b| a.saturating_mul(b),
+                ("3", "5") -> "15"
+            );
+        )*
+    };
+}
+
+gen_nz_saturating_mul_macros!(
+    saturating_mul_i8: i8,
+    saturating_mul_i16: i16,
+    saturating_mul_i32: i32,
+    saturating_mul_i64: i64,
+    saturating_mul_i128: i128,
+    saturating_mul_isize: isize,
+    saturating_mul_u8: u8,
+    saturating_mul_u16: u16,
+    saturating_mul_u32: u32,
+    saturating_mul_u64: u64,
+    saturating_mul_u128: u128,
+    saturating_mul_usize: usize
+);
+
+/// Generates a non-zero subtraction macro that saturates at one for the
+/// specified unsigned integer type.
+macro_rules! gen_nz_saturating_sub_to_one_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Computes the subtraction of two literals, constant values or expressions that evaluate to")]
+        #[doc = concat!("non-zero [`prim@", stringify!($int_type), "`] saturating at one as a")]
+        #[doc = concat!("[`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// The result is `max(1, a - b)` computed without underflow, so it is
+        /// never zero and only the arguments are checked. This matches the
+        /// semantics of counters that are always at least one. If any of the
+        /// arguments evaluates to zero, a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literals
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(5, 3);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(3, ", stringify!($int_type), "::MAX);")]
+        /// # assert_eq!(2, NZ.get());
+        /// # assert_eq!(1, nz.get());
+        /// ```
+        ///
+        /// #### Zero argument fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(5, 0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($a_expr:expr, $b_expr:expr) => {
+                const {
+                    let a = $crate::__nz_operand!($macro_name, $int_type, $a_expr);
+                    let b = $crate::__nz_operand!($macro_name, $int_type, $b_expr);
+                    match core::num::NonZero::<$int_type>::new(a.get().saturating_sub(b.get())) {
+                        Some(difference) => difference,
+                        None => core::num::NonZero::<$int_type>::MIN,
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero subtraction macro that saturates at one from each
+/// macro name and unsigned integer type pair.
+macro_rules! gen_nz_saturating_sub_to_one_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_saturating_sub_to_one_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_saturating_sub_to_one_macros!(
+    saturating_sub_to_one_u8: u8,
+    saturating_sub_to_one_u16: u16,
+    saturating_sub_to_one_u32: u32,
+    saturating_sub_to_one_u64: u64,
+    saturating_sub_to_one_u128: u128,
+    saturating_sub_to_one_usize: usize
+);
+
+/// Generates a non-zero next power of two macro for the specified unsigned
+/// integer type.
+macro_rules! gen_nz_next_power_of_two_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Computes the smallest power of two greater than or equal to a literal,")]
+        #[doc = concat!("constant value or expression that evaluates to non-zero [`prim@", stringify!($int_type), "`] as a")]
+        #[doc = concat!("[`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// A power of two is always non-zero, therefore only the argument is
+        /// checked. If the argument evaluates to zero or the next power of two
+        /// overflows, a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literal
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(100);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(NZ.get());")]
+        /// # assert_eq!(128, NZ.get());
+        /// # assert_eq!(128, nz.get());
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        ///
+        /// #### Overflowing next power of two fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {
+                const {
+                    let value = $crate::__nz_operand!($macro_name, $int_type, $int_expr);
+                    match value.checked_next_power_of_two() {
+                        Some(power) => power,
+                        None => panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: next power of two overflowed"
+                        )),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero next power of two macro from each macro name and
+/// unsigned integer type pair.
+macro_rules! gen_nz_next_power_of_two_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_next_power_of_two_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_next_power_of_two_macros!(
+    next_power_of_two_u8: u8,
+    next_power_of_two_u16: u16,
+    next_power_of_two_u32: u32,
+    next_power_of_two_u64: u64,
+    next_power_of_two_u128: u128,
+    next_power_of_two_usize: usize
+);
+
+/// Generates a logarithm macro with non-zero argument for the specified
+/// unsigned integer type.
+macro_rules! gen_nz_ilog_macro {
+    ($macro_name:ident, $int_type:ident, $method:ident, $base:literal, $example:literal -> $log:literal) => {
+        #[doc = concat!("Computes the base ", $base, " logarithm of a literal, constant value or expression that")]
+        #[doc = concat!("evaluates to non-zero [`prim@", stringify!($int_type), "`] at compile time, rounded down.")]
+        #[doc = r""]
+        #[doc = concat!("Unlike [`", stringify!($int_type), "::", stringify!($method), "`], which panics at runtime for zero, the")]
+        /// argument is checked at compile time, so a zero argument results in a
+        /// [`panic`] at compile time. Since the logarithm can be zero, the
+        /// result is a [`prim@u32`] instead of a [`NonZero`][`core::num::NonZero`].
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literal
+        /// ```rust
+        #[doc = concat!(" const LOG: u32 = nz::", stringify!($macro_name), "!(", $example, ");")]
+        #[doc = concat!(" let log = nz::", stringify!($macro_name), "!(1);")]
+        #[doc = concat!(" # assert_eq!(", $log, ", LOG);")]
+        /// # assert_eq!(0, log);
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {
+                const { $crate::__nz_operand!($macro_name, $int_type, $int_expr).$method() }
+            };
+        }
+    };
+}
+
+/// Generates base 2 and base 10 logarithm macros with non-zero argument from
+/// each macro name pair and unsigned integer type.
+macro_rules! gen_nz_ilog_macros {
+    ($($ilog2_name:ident, $ilog10_name:ident: $int_type:ident), *) => {
+        $(
+            gen_nz_ilog_macro!($ilog2_name, $int_type, ilog2, "2", "100" -> "6");
+            gen_nz_ilog_macro!($ilog10_name, $int_type, ilog10, "10", "100" -> "2");
+        )*
+    };
+}
+
+gen_nz_ilog_macros!(
+    ilog2_u8, ilog10_u8: u8,
+    ilog2_u16, ilog10_u16: u16,
+    ilog2_u32, ilog10_u32: u32,
+    ilog2_u64, ilog10_u64: u64,
+    ilog2_u128, ilog10_u128: u128,
+    ilog2_usize, ilog10_usize: usize
+);
+
+/// Generates a variadic non-zero arithmetic macro for the specified integer
+/// type that folds its arguments with a checked operation.
+macro_rules! gen_nz_fold_macro {
+    (
+        $d:tt $macro_name:ident, $int_type:ident, $result_name:literal, $op_name:literal,
+        |$acc:ident, $term:ident| $op:expr,
+        ($example_args:expr) -> $example:expr
+    ) => {
+        #[doc = concat!("Computes the ", $result_name, " of one or more literals, constant values or")]
+        #[doc = concat!("expressions that evaluate to non-zero [`prim@", stringify!($int_type), "`] as a")]
+        #[doc = concat!("[`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        #[doc = concat!("The ", $result_name, " of non-zero values is always non-zero, therefore only the")]
+        /// arguments are checked. If any of the arguments evaluates to zero or
+        #[doc = concat!("the ", $op_name, " overflows, a [`panic`] will occur at compile time.")]
+        /// A trailing comma is accepted.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant expressions
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(", $example_args, ");")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(NZ.get(),);")]
+        #[doc = concat!(" # assert_eq!(", $example, ", NZ.get());")]
+        /// # assert_eq!(NZ, nz);
+        /// ```
+        ///
+        /// #### Zero argument fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(1, 0, 2);")]
+        /// ```
+        ///
+        #[doc = concat!("#### Overflowing ", $op_name, " fails to compile")]
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(2, ", stringify!($int_type), "::MAX);")]
+        /// ```
+        ///
+        /// #### No arguments fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!();")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($d first_expr:expr $d(, $d rest_expr:expr)* $d(,)?) => {
+                const {
+                    let $acc = $crate::__nz_operand!($macro_name, $int_type, $d first_expr);
+                    $d(
+                        let $term = $crate::__nz_operand!($macro_name, $int_type, $d rest_expr);
+                        let $acc = match $op {
+                            Some(result) => result,
+                            None => panic!(concat!(
+                                "nz::", stringify!($macro_name), "!: ", $op_name, " overflowed"
+                            )),
+                        };
+                    )*
+                    $acc
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero sum macro from each macro name and unsigned integer
+/// type pair.
+macro_rules! gen_nz_sum_macros {
+    ($d:tt $($macro_name:ident: $int_type:ident), *) => {
+        $(
+            gen_nz_fold_macro!(
+                $d $macro_name, $int_type, "sum", "addition",
+                |sum, term| sum.checked_add(term.get()),
+                ("2, 3, 4") -> "9"
+            );
+        )*
+    };
+}
+
+gen_nz_sum_macros!(
+    $
+    sum_u8: u8,
+    sum_u16: u16,
+    sum_u32: u32,
+    sum_u64: u64,
+    sum_u128: u128,
+    sum_usize: usize
+);
+
+/// Generates a non-zero product macro from each macro name and integer type
+/// pair.
+macro_rules! gen_nz_product_macros {
+    ($d:tt $($macro_name:ident: $int_type:ident), *) => {
+        $(
+            gen_nz_fold_macro!(
+                $d $macro_name, $int_type, "product", "multiplication",
+                |product, factor| product.checked_mul(factor),
+                ("2, 3, 4") -> "24"
+            );
+        )*
+    };
+}
+
+gen_nz_product_macros!(
+    $
+    product_i8: i8,
+    product_i16: i16,
+    product_i32: i32,
+    product_i64: i64,
+    product_i128: i128,
+    product_isize: isize,
+    product_u8: u8,
+    product_u16: u16,
+    product_u32: u32,
+    product_u64: u64,
+    product_u128: u128,
+    product_usize: usize
+);
+
+/// Generates a Euclidean remainder macro with a non-zero divisor for the
+/// specified signed integer type.
+macro_rules! gen_nz_rem_euclid_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Computes the Euclidean remainder of a literal, constant value or expression that")]
+        #[doc = concat!("evaluates to [`prim@", stringify!($int_type), "`] divided by a non-zero [`prim@", stringify!($int_type), "`] at compile time.")]
+        #[doc = r""]
+        /// Only the divisor is required to be non-zero, so the division is
+        #[doc = concat!("always defined. The remainder can be zero, therefore it is a [`prim@", stringify!($int_type), "`] in")]
+        #[doc = concat!("the range `0..divisor.abs()`. If the divisor evaluates to zero or the result")]
+        #[doc = concat!("overflows (i.e. `", stringify!($int_type), "::MIN` divided by `-1`), a [`panic`] will occur at compile time.")]
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literals
+        /// ```rust
+        #[doc = concat!(" const REM: ", stringify!($int_type), " = nz::", stringify!($macro_name), "!(-7, 4);")]
+        #[doc = concat!(" let rem = nz::", stringify!($macro_name), "!(REM + 5, -3);")]
+        /// # assert_eq!(1, REM);
+        /// # assert_eq!(0, rem);
+        /// ```
+        ///
+        /// #### Zero divisor fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(7, 0);")]
+        /// ```
+        ///
+        /// #### Overflowing remainder fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MIN, -1);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($a_expr:expr, $b_expr:expr) => {
+                const {
+                    let a: $int_type = $a_expr;
+                    let b = $crate::__nz_operand!($macro_name, $int_type, $b_expr);
+                    match a.checked_rem_euclid(b.get()) {
+                        Some(rem) => rem,
+                        None => panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: remainder overflowed"
+                        )),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a Euclidean remainder macro with a non-zero divisor from each
+/// macro name and signed integer type pair.
+macro_rules! gen_nz_rem_euclid_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_rem_euclid_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_rem_euclid_macros!(
+    rem_euclid_i8: i8,
+    rem_euclid_i16: i16,
+    rem_euclid_i32: i32,
+    rem_euclid_i64: i64,
+    rem_euclid_i128: i128,
+    rem_euclid_isize: isize
+);
+
+/// Generates a non-zero exact division macro for the specified unsigned integer
+/// type.
+macro_rules! gen_nz_div_exact_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Divides two literals, constant values or expressions that evaluate to non-zero")]
+        #[doc = concat!("[`prim@", stringify!($int_type), "`] exactly, resulting in a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// If any of the arguments evaluates to zero, the dividend is not
+        /// divisible by the divisor or the quotient is zero (i.e. the dividend
+        /// is less than the divisor), a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant values
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const TOTAL: ", stringify!($int_type), " = 96;")]
+        #[doc = concat!(" const CHUNK: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(TOTAL, 4);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(TOTAL, CHUNK.get());")]
+        /// # assert_eq!(24, CHUNK.get());
+        /// # assert_eq!(4, nz.get());
+        /// ```
+        ///
+        /// #### Inexact division fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(10, 4);")]
+        /// ```
+        ///
+        /// #### Zero divisor fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(10, 0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($a_expr:expr, $b_expr:expr) => {
+                const {
+                    let a = $crate::__nz_operand!($macro_name, $int_type, $a_expr);
+                    let b = $crate::__nz_operand!($macro_name, $int_type, $b_expr);
+                    if a.get() % b.get() != 0 {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: `", stringify!($a_expr),
+                            "` is not divisible by `", stringify!($b_expr), "`"
+                        ));
+                    }
+                    match core::num::NonZero::<$int_type>::new(a.get() / b.get()) {
+                        Some(quotient) => quotient,
+                        // a non-zero dividend that is divisible by the divisor
+                        // is at least as large as the divisor
+                        None => unreachable!(),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero exact division macro from each macro name and unsigned
+/// integer type pair.
+macro_rules! gen_nz_div_exact_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_div_exact_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_div_exact_macros!(
+    div_exact_u8: u8,
+    div_exact_u16: u16,
+    div_exact_u32: u32,
+    div_exact_u64: u64,
+    div_exact_u128: u128,
+    div_exact_usize: usize
+);
+
+/// Generates a non-zero rounded ratio macro for the specified unsigned integer
+/// type.
+macro_rules! gen_nz_from_ratio_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Computes the rounded ratio `a * num / den` of literals, constant values or expressions")]
+        #[doc = concat!("that evaluate to [`prim@", stringify!($int_type), "`] as a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// The denominator must be non-zero and the result is rounded to the
+        /// nearest integer, where halves are rounded up. The multiplication is
+        /// done in [`u128`], so the intermediate product can exceed the range of
+        /// the integer type. If the denominator or the result is zero, or any of
+        /// the intermediate values or the result overflows, a [`panic`] will
+        /// occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literals
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(100, 2, 3);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX, 1, 2);")]
+        /// # assert_eq!(67, NZ.get());
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::MAX / 2 + 1, nz.get());")]
+        /// ```
+        ///
+        /// #### Zero denominator fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(100, 2, 0);")]
+        /// ```
+        ///
+        /// #### Zero result fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(1, 1, 3);")]
+        /// ```
+        ///
+        /// #### Overflowing result fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX, 3, 2);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($a_expr:expr, $num_expr:expr, $den_expr:expr) => {
+                const {
+                    let a: $int_type = $a_expr;
+                    let num: $int_type = $num_expr;
+                    let den = $crate::__nz_operand!($macro_name, $int_type, $den_expr).get() as u128;
+                    let product = match (a as u128).checked_mul(num as u128) {
+                        Some(product) => product,
+                        None => panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: multiplication overflowed"
+                        )),
+                    };
+                    // rounding with the remainder instead of adding half of the
+                    // denominator to the product, which could overflow
+                    let rem = product % den;
+                    let ratio = product / den + (rem >= den - rem) as u128;
+                    if ratio > <$int_type>::MAX as u128 {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: ratio too large to fit in ",
+                            stringify!($int_type)
+                        ));
+                    }
+                    match core::num::NonZero::<$int_type>::new(ratio as $int_type) {
+                        Some(non_zero) => non_zero,
+                        None => panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: expected non-zero ",
+                            stringify!($int_type), " ratio"
+                        )),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero rounded ratio macro from each macro name and unsigned
+/// integer type pair.
+macro_rules! gen_nz_from_ratio_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_from_ratio_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_from_ratio_macros!(
+    from_ratio_u8: u8,
+    from_ratio_u16: u16,
+    from_ratio_u32: u32,
+    from_ratio_u64: u64,
+    from_ratio_usize: usize
+);
+
+/// Rounds a literal, constant value or expression that evaluates to non-zero
+/// [`prim@usize`] up to a multiple of an alignment resulting in a
+/// [`NonZero<usize>`][`core::num::NonZero`] at compile time.
+///
+/// The alignment must be a power of two, which is always non-zero. Rounding a
+/// non-zero value up results in a non-zero value, therefore only the arguments
+/// are checked. If the value evaluates to zero, the alignment is not a power
+/// of two or the rounding overflows, a [`panic`] will occur at compile time.
+///
+/// # Examples
+///
+/// #### From constant expressions
+/// ```rust
+/// # use std::num::NonZero;
+/// const SIZE: NonZero<usize> = nz::align_up_usize!(13, 8);
+/// let size = nz::align_up_usize!(SIZE.get(), core::mem::align_of::<u64>());
+/// # assert_eq!(16, SIZE.get());
+/// # assert_eq!(16, size.get());
+/// ```
+///
+/// #### Alignment that is not a power of two fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::align_up_usize!(13, 12);
+/// ```
+///
+/// #### Overflowing rounding fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::align_up_usize!(usize::MAX, 2);
+/// ```
+#[macro_export]
+macro_rules! align_up_usize {
+    ($int_expr:expr, $align_expr:expr) => {
+        const {
+            let value = $crate::__nz_operand!(align_up_usize, usize, $int_expr);
+            let align: usize = $align_expr;
+            if !align.is_power_of_two() {
+                panic!(concat!(
+                    "nz::align_up_usize!: alignment `",
+                    stringify!($align_expr),
+                    "` is not a power of two"
+                ));
+            }
+            match value.get().checked_add(align - 1) {
+                Some(sum) => {
+                    match core::num::NonZero::<usize>::new(sum & !(align - 1)) {
+                        Some(aligned) => aligned,
+                        // rounding a non-zero value up cannot result in zero
+                        None => unreachable!(),
+                    }
+                }
+                None => panic!("nz::align_up_usize!: rounding overflowed"),
+            }
+        }
+    };
+}
+
+/// Generates a non-zero wrapping negation macro for the specified signed
+/// integer type.
+macro_rules! gen_nz_wrapping_neg_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Computes the wrapping negation of a literal, constant value or expression that")]
+        #[doc = concat!("evaluates to non-zero [`prim@", stringify!($int_type), "`] as a")]
+        #[doc = concat!("[`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// The negation of a non-zero value is always non-zero, including
+        #[doc = concat!("[`", stringify!($int_type), "::MIN`], which wraps around to itself, therefore only the argument is")]
+        /// checked. If the argument evaluates to zero, a [`panic`] will occur at
+        /// compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literal
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(27);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MIN);")]
+        /// # assert_eq!(-27, NZ.get());
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::MIN, nz.get());")]
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {
+                const { $crate::__nz_operand!($macro_name, $int_type, $int_expr).wrapping_neg() }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero wrapping negation macro from each macro name and
+/// signed integer type pair.
+macro_rules! gen_nz_wrapping_neg_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_wrapping_neg_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_wrapping_neg_macros!(
+    wrapping_neg_i8: i8,
+    wrapping_neg_i16: i16,
+    wrapping_neg_i32: i32,
+    wrapping_neg_i64: i64,
+    wrapping_neg_i128: i128,
+    wrapping_neg_isize: isize
+);
+
+/// Generates a non-zero scaling macro for the specified unsigned integer type
+/// that is at most 64 bits wide.
+macro_rules! gen_nz_scale_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Scales a literal, constant value or expression that evaluates to non-zero [`prim@", stringify!($int_type), "`]")]
+        #[doc = concat!("by the ratio `num / den` of non-zero [`prim@", stringify!($int_type), "`] resulting in a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// The result is `value * num / den` rounded down, where the
+        /// multiplication is done in [`u128`], so the intermediate product can
+        /// exceed the range of the integer type. If any of the arguments or the
+        /// result is zero, or the intermediate product or the result overflows,
+        /// a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literals
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(100, 3, 4);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX, 3, 4);")]
+        /// # assert_eq!(75, NZ.get());
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::MAX / 4 * 3 + 2, nz.get());")]
+        /// ```
+        ///
+        /// #### Zero result fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(1, 1, 2);")]
+        /// ```
+        ///
+        /// #### Overflowing result fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX, 4, 3);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr, $num_expr:expr, $den_expr:expr) => {
+                const {
+                    let value = $crate::__nz_operand!($macro_name, $int_type, $int_expr).get() as u128;
+                    let num = $crate::__nz_operand!($macro_name, $int_type, $num_expr).get() as u128;
+                    let den = $crate::__nz_operand!($macro_name, $int_type, $den_expr).get() as u128;
+                    let scaled = match value.checked_mul(num) {
+                        Some(product) => product / den,
+                        None => panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: multiplication overflowed"
+                        )),
+                    };
+                    if scaled > <$int_type>::MAX as u128 {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: result too large to fit in ",
+                            stringify!($int_type)
+                        ));
+                    }
+                    match core::num::NonZero::<$int_type>::new(scaled as $int_type) {
+                        Some(non_zero) => non_zero,
+                        None => panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: expected non-zero ",
+                            stringify!($int_type), " result"
+                        )),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero scaling macro from each macro name and unsigned integer
+/// type pair.
+macro_rules! gen_nz_scale_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_scale_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_scale_macros!(
+    scale_u8: u8,
+    scale_u16: u16,
+    scale_u32: u32,
+    scale_u64: u64,
+    scale_usize: usize
+);
+
+/// Generates a non-zero wrapping addition macro for the specified unsigned
+/// integer type.
+macro_rules! gen_nz_wrapping_add_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Computes the wrapping addition of two literals, constant values or expressions that")]
+        #[doc = concat!("evaluate to non-zero [`prim@", stringify!($int_type), "`] as a")]
+        #[doc = concat!("[`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// The sum of two non-zero unsigned values is zero only if the addition
+        #[doc = concat!("wraps around exactly to zero (e.g. `", stringify!($int_type), "::MAX + 1`), therefore the result is")]
+        /// checked again. If any of the arguments or the wrapped sum evaluates to
+        /// zero, a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literals
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(3, 5);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX, 3);")]
+        /// # assert_eq!(8, NZ.get());
+        /// # assert_eq!(2, nz.get());
+        /// ```
+        ///
+        /// #### Zero argument fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(3, 0);")]
+        /// ```
+        ///
+        /// #### Sum wrapping to zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX, 1);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($a_expr:expr, $b_expr:expr) => {
+                const {
+                    let a = $crate::__nz_operand!($macro_name, $int_type, $a_expr);
+                    let b = $crate::__nz_operand!($macro_name, $int_type, $b_expr);
+                    match core::num::NonZero::<$int_type>::new(a.get().wrapping_add(b.get())) {
+                        Some(sum) => sum,
+                        None => panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: addition wrapped around to zero"
+                        )),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero wrapping addition macro from each macro name and
+/// unsigned integer type pair.
+macro_rules! gen_nz_wrapping_add_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_wrapping_add_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_wrapping_add_macros!(
+    wrapping_add_u8: u8,
+    wrapping_add_u16: u16,
+    wrapping_add_u32: u32,
+    wrapping_add_u64: u64,
+    wrapping_add_u128: u128,
+    wrapping_add_usize: usize
+);
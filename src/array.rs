@@ -0,0 +1,308 @@
+//! Macros that create arrays of [`NonZero`][`core::num::NonZero`].
+
+/// Generates a non-zero array macro for the specified integer type.
+macro_rules! gen_nz_array_macro {
+    ($d:tt $macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates an array of [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] from a")]
+        #[doc = r"comma-separated list of literals, constant values or expressions"]
+        #[doc = concat!("that evaluate to [`prim@", stringify!($int_type), "`].")]
+        #[doc = r""]
+        /// Each element is checked individually and if any of them evaluates to
+        /// zero, a [`panic`] will occur at compile time with a message that
+        /// contains the source of the element. A trailing comma is accepted.
+        ///
+        /// The `[value; N]` repeat syntax is also supported, in which case the
+        /// value is checked once and `N` must be a constant expression that can
+        /// be used as an array length.
+        ///
+        /// # Examples
+        ///
+        /// #### From list of elements
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NUM: ", stringify!($int_type), " = 7;")]
+        #[doc = concat!(" const NZS: [NonZero<", stringify!($int_type), ">; 3] = nz::", stringify!($macro_name), "![1, NUM, 0x10,];")]
+        #[doc = concat!(" let nzs = nz::", stringify!($macro_name), "![NUM + 1, NUM - 1];")]
+        /// # assert_eq!([1, 7, 0x10], NZS.map(NonZero::get));
+        /// # assert_eq!([8, 6], nzs.map(NonZero::get));
+        /// ```
+        ///
+        /// #### From repeated element
+        /// ```rust
+        /// # use std::num::NonZero;
+        /// const LEN: usize = 16;
+        #[doc = concat!(" const NZS: [NonZero<", stringify!($int_type), ">; LEN] = nz::", stringify!($macro_name), "![4; LEN];")]
+        #[doc = concat!(" let nzs = nz::", stringify!($macro_name), "![1 + 1; 2 * 2];")]
+        /// # assert_eq!([4; LEN], NZS.map(NonZero::get));
+        /// # assert_eq!([2; 4], nzs.map(NonZero::get));
+        /// ```
+        ///
+        /// #### Zero element fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "![1, 2, 0];")]
+        /// ```
+        ///
+        /// #### Zero repeated element fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "![0; 8];")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($d int_expr:expr; $d len_expr:expr) => {
+                const {
+                    [$crate::$int_type!(
+                        $d int_expr,
+                        concat!(
+                            "nz::", stringify!($macro_name), "!: expected non-zero ",
+                            stringify!($int_type), " element `", stringify!($d int_expr), "`"
+                        )
+                    ); $d len_expr]
+                }
+            };
+            ($d($d int_expr:expr),* $d(,)?) => {
+                const {
+                    [$d(
+                        $crate::$int_type!(
+                            $d int_expr,
+                            concat!(
+                                "nz::", stringify!($macro_name), "!: expected non-zero ",
+                                stringify!($int_type), " element `", stringify!($d int_expr), "`"
+                            )
+                        )
+                    ),*]
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero array macro from each macro name and integer type
+/// pair.
+macro_rules! gen_nz_array_macros {
+    ($d:tt $($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_array_macro!($d $macro_name, $int_type);)*
+    };
+}
+
+gen_nz_array_macros!(
+    $
+    i8_array: i8,
+    i16_array: i16,
+    i32_array: i32,
+    i64_array: i64,
+    i128_array: i128,
+    isize_array: isize,
+    u8_array: u8,
+    u16_array: u16,
+    u32_array: u32,
+    u64_array: u64,
+    u128_array: u128,
+    usize_array: usize
+);
+
+/// Generates a fallible non-zero array macro for the specified integer type.
+macro_rules! gen_try_nz_array_macro {
+    ($d:tt $macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates an array of [`Option<NonZero<", stringify!($int_type), ">>`][`core::num::NonZero`] from a")]
+        #[doc = r"comma-separated list of expressions that evaluate to"]
+        #[doc = concat!("[`prim@", stringify!($int_type), "`].")]
+        #[doc = r""]
+        /// Each element is converted with [`NonZero::new`][`core::num::NonZero::new`],
+        /// so zero elements become [`None`] instead of failing to compile.
+        /// The elements are not required to be constant, which makes the macro
+        /// usable with runtime values. A trailing comma is accepted.
+        ///
+        /// # Examples
+        ///
+        /// #### From runtime values
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" let bytes: [", stringify!($int_type), "; 3] = [1, 0, 7];")]
+        #[doc = concat!(" let nzs: [Option<NonZero<", stringify!($int_type), ">>; 3] = nz::", stringify!($macro_name), "![bytes[0], bytes[1], bytes[2],];")]
+        /// # assert_eq!([Some(1), None, Some(7)], nzs.map(|nz| nz.map(NonZero::get)));
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($d($d int_expr:expr),* $d(,)?) => {
+                [$d(core::num::NonZero::<$int_type>::new($d int_expr)),*]
+            };
+        }
+    };
+}
+
+/// Generates a fallible non-zero array macro from each macro name and integer
+/// type pair.
+macro_rules! gen_try_nz_array_macros {
+    ($d:tt $($macro_name:ident: $int_type:ident), *) => {
+        $(gen_try_nz_array_macro!($d $macro_name, $int_type);)*
+    };
+}
+
+gen_try_nz_array_macros!(
+    $
+    i8_array_try: i8,
+    i16_array_try: i16,
+    i32_array_try: i32,
+    i64_array_try: i64,
+    i128_array_try: i128,
+    isize_array_try: isize,
+    u8_array_try: u8,
+    u16_array_try: u16,
+    u32_array_try: u32,
+    u64_array_try: u64,
+    u128_array_try: u128,
+    usize_array_try: usize
+);
+
+/// Creates an array of the specified non-zero length filled with a value.
+///
+/// The first argument is the length of the array, which must be a constant
+/// [`usize`] expression, including const generic parameters (e.g. `N`). The
+/// second argument is the value of each element, which must be [`Copy`] or a
+/// constant. If the length is zero, a [`panic`] will occur at compile time,
+/// which in generic code happens when the function is instantiated with a zero
+/// length.
+///
+/// # Examples
+///
+/// #### With const generic length
+/// ```rust
+/// fn non_empty<const N: usize>(fill: u8) -> [u8; N] {
+///     nz::array_len!(N, fill)
+/// }
+///
+/// const LEN: usize = 4;
+/// let array: [u8; LEN] = non_empty(7);
+/// let nzs = nz::array_len!(2, nz::u16!(3));
+/// # assert_eq!([7; LEN], array);
+/// # assert_eq!([3; 2], nzs.map(core::num::NonZero::get));
+/// ```
+///
+/// #### Zero length fails to compile
+/// ```rust, compile_fail
+/// let _: [u8; 0] = nz::array_len!(0, 1);
+/// ```
+///
+/// #### Zero const generic length fails to compile
+/// ```rust, compile_fail
+/// fn non_empty<const N: usize>() -> [u8; N] {
+///     nz::array_len!(N, 1)
+/// }
+///
+/// let _ = non_empty::<0>();
+/// ```
+#[macro_export]
+macro_rules! array_len {
+    ($len_expr:expr, $fill_expr:expr) => {{
+        const {
+            if $len_expr == 0 {
+                panic!(concat!(
+                    "nz::array_len!: expected non-zero array length `",
+                    stringify!($len_expr),
+                    "`"
+                ));
+            }
+        }
+        [$fill_expr; $len_expr]
+    }};
+}
+
+/// Creates an array of [`NonZero<u8>`][`core::num::NonZero`] from a constant
+/// byte string or byte array reference.
+///
+/// Every byte is checked, so the result is guaranteed to contain no NUL byte,
+/// which is useful for C string-like tables. The argument must be a constant
+/// `&[u8; N]` (e.g. a byte string literal). If any of the bytes is zero, a
+/// [`panic`] will occur at compile time with the index of the first zero byte
+/// in the message.
+///
+/// # Examples
+///
+/// #### From byte string literal
+/// ```rust
+/// # use std::num::NonZero;
+/// const NAME: [NonZero<u8>; 3] = nz::bytes_nonzero!(b"abc");
+/// let bytes = nz::bytes_nonzero!(&[1, 2]);
+/// # assert_eq!(*b"abc", NAME.map(NonZero::get));
+/// # assert_eq!([1, 2], bytes.map(NonZero::get));
+/// ```
+///
+/// #### Zero byte fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::bytes_nonzero!(b"a\0c");
+/// ```
+#[macro_export]
+macro_rules! bytes_nonzero {
+    ($bytes_expr:expr) => {
+        const {
+            $crate::__private::non_zero_array_u8(
+                $bytes_expr,
+                "nz::bytes_nonzero!: expected non-zero byte at index ",
+            )
+        }
+    };
+}
+
+/// Generates a non-zero array validation macro for the specified integer type.
+macro_rules! gen_nz_validate_all_macro {
+    ($macro_name:ident, $int_type:ident, $fn_name:ident) => {
+        #[doc = concat!("Converts a reference to a constant array of [`prim@", stringify!($int_type), "`] to an array of")]
+        #[doc = concat!("[`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// Every element is checked in a constant loop and the array is rebuilt
+        /// from the non-zero values, so existing tables can be converted without
+        /// calling a macro for each element. The argument must be a constant
+        /// `&[T; N]`. If any of the elements is zero, a [`panic`] will occur at
+        /// compile time with the index of the first zero element in the message.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant array
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const SIZES: [", stringify!($int_type), "; 3] = [1, 2, 4];")]
+        #[doc = concat!(" const NZ_SIZES: [NonZero<", stringify!($int_type), ">; 3] = nz::", stringify!($macro_name), "!(&SIZES);")]
+        /// # assert_eq!(SIZES, NZ_SIZES.map(NonZero::get));
+        /// ```
+        ///
+        /// #### Zero element fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" const SIZES: [", stringify!($int_type), "; 3] = [1, 0, 4];")]
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(&SIZES);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($array_expr:expr) => {
+                const {
+                    $crate::__private::$fn_name(
+                        $array_expr,
+                        concat!("nz::", stringify!($macro_name), "!: expected non-zero element at index "),
+                    )
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero array validation macro from each macro name, integer
+/// type and conversion function triple.
+macro_rules! gen_nz_validate_all_macros {
+    ($($macro_name:ident: $int_type:ident => $fn_name:ident), *) => {
+        $(gen_nz_validate_all_macro!($macro_name, $int_type, $fn_name);)*
+    };
+}
+
+gen_nz_validate_all_macros!(
+    validate_all_i8: i8 => non_zero_array_i8,
+    validate_all_i16: i16 => non_zero_array_i16,
+    validate_all_i32: i32 => non_zero_array_i32,
+    validate_all_i64: i64 => non_zero_array_i64,
+    validate_all_i128: i128 => non_zero_array_i128,
+    validate_all_isize: isize => non_zero_array_isize,
+    validate_all_u8: u8 => non_zero_array_u8,
+    validate_all_u16: u16 => non_zero_array_u16,
+    validate_all_u32: u32 => non_zero_array_u32,
+    validate_all_u64: u64 => non_zero_array_u64,
+    validate_all_u128: u128 => non_zero_array_u128,
+    validate_all_usize: usize => non_zero_array_usize
+);
@@ -0,0 +1,278 @@
+//! Macros that assert properties of non-zero values at compile time.
+
+/// Generates a non-zero assertion macro for the specified integer type.
+macro_rules! gen_nz_assert_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Asserts that a literal, constant value or expression evaluates to non-zero")]
+        #[doc = concat!("[`prim@", stringify!($int_type), "`] at compile time.")]
+        #[doc = r""]
+        #[doc = concat!("It is the same check as [`nz::", stringify!($int_type), "!`][`crate::", stringify!($int_type), "`], but the created value")]
+        /// is discarded and the expansion evaluates to `()`, so it can be used
+        /// for compile-time invariant checks (e.g. in `const _: () = ...;`
+        /// items). If the argument evaluates to zero, a [`panic`] will occur at
+        /// compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### Assertion in constant item
+        /// ```rust
+        #[doc = concat!(" const CAPACITY: ", stringify!($int_type), " = 16;")]
+        #[doc = concat!(" const _: () = nz::", stringify!($macro_name), "!(CAPACITY);")]
+        /// const _: () = {
+        #[doc = concat!("     nz::", stringify!($macro_name), "!(CAPACITY / 2);")]
+        #[doc = concat!("     nz::", stringify!($macro_name), "!(CAPACITY - 1);")]
+        /// };
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" const CAPACITY: ", stringify!($int_type), " = 0;")]
+        #[doc = concat!(" const _: () = nz::", stringify!($macro_name), "!(CAPACITY);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {{
+                let _ = $crate::$int_type!(
+                    $int_expr,
+                    concat!(
+                        "nz::", stringify!($macro_name), "!: expected non-zero ",
+                        stringify!($int_type), " value"
+                    )
+                );
+            }};
+        }
+    };
+}
+
+/// Generates a non-zero assertion macro from each macro name and integer type
+/// pair.
+macro_rules! gen_nz_assert_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_assert_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_assert_macros!(
+    assert_nz_i8: i8,
+    assert_nz_i16: i16,
+    assert_nz_i32: i32,
+    assert_nz_i64: i64,
+    assert_nz_i128: i128,
+    assert_nz_isize: isize,
+    assert_nz_u8: u8,
+    assert_nz_u16: u16,
+    assert_nz_u32: u32,
+    assert_nz_u64: u64,
+    assert_nz_u128: u128,
+    assert_nz_usize: usize
+);
+
+/// Generates a non-zero macro with source location in the panic message for
+/// the specified integer type.
+macro_rules! gen_nz_debug_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] from a literal, a constant value or")]
+        #[doc = concat!("expression that evaluates to [`prim@", stringify!($int_type), "`] with the source location in the panic message.")]
+        #[doc = r""]
+        #[doc = concat!("It is the same as [`nz::", stringify!($int_type), "!`][`crate::", stringify!($int_type), "`], but if the argument evaluates to zero, the")]
+        /// message of the compile-time [`panic`] contains the argument and the
+        /// file and line of the invocation (e.g. `src/table.rs:42`), which helps
+        /// to find the zero entry of a large table.
+        ///
+        /// # Examples
+        ///
+        /// #### In constant table
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const TABLE: [NonZero<", stringify!($int_type), ">; 2] = [")]
+        #[doc = concat!("     nz::", stringify!($macro_name), "!(1),")]
+        #[doc = concat!("     nz::", stringify!($macro_name), "!(2),")]
+        /// ];
+        /// # assert_eq!([1, 2], TABLE.map(NonZero::get));
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {
+                $crate::$int_type!(
+                    $int_expr,
+                    concat!(
+                        "nz::", stringify!($macro_name), "!: expected non-zero ",
+                        stringify!($int_type), " value `", stringify!($int_expr), "` at ",
+                        file!(), ":", line!()
+                    )
+                )
+            };
+        }
+    };
+}
+
+/// Generates a non-zero macro with source location in the panic message from
+/// each macro name and integer type pair.
+macro_rules! gen_nz_debug_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_debug_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_debug_macros!(
+    debug_i8: i8,
+    debug_i16: i16,
+    debug_i32: i32,
+    debug_i64: i64,
+    debug_i128: i128,
+    debug_isize: isize,
+    debug_u8: u8,
+    debug_u16: u16,
+    debug_u32: u32,
+    debug_u64: u64,
+    debug_u128: u128,
+    debug_usize: usize
+);
+
+/// Generates a non-zero equality assertion macro for the specified integer
+/// type.
+macro_rules! gen_nz_assert_eq_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Asserts that two literals, constant values or expressions that evaluate to non-zero")]
+        #[doc = concat!("[`prim@", stringify!($int_type), "`] are equal at compile time.")]
+        #[doc = r""]
+        /// The expansion evaluates to `()`, so it can be used for compile-time
+        /// invariant checks (e.g. in `const _: () = ...;` items). If any of the
+        /// arguments evaluates to zero or the arguments are not equal, a
+        /// [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### Assertion in constant item
+        /// ```rust
+        #[doc = concat!(" const BUFFER_LEN: ", stringify!($int_type), " = 16;")]
+        #[doc = concat!(" const CHUNK_LEN: ", stringify!($int_type), " = 4;")]
+        #[doc = concat!(" const _: () = nz::", stringify!($macro_name), "!(BUFFER_LEN, CHUNK_LEN * 4);")]
+        /// ```
+        ///
+        /// #### Zero argument fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" const _: () = nz::", stringify!($macro_name), "!(0, 0);")]
+        /// ```
+        ///
+        /// #### Different arguments fail to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" const _: () = nz::", stringify!($macro_name), "!(16, 8);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($left_expr:expr, $right_expr:expr) => {
+                const {
+                    let left = $crate::__nz_operand!($macro_name, $int_type, $left_expr);
+                    let right = $crate::__nz_operand!($macro_name, $int_type, $right_expr);
+                    if left.get() != right.get() {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: expected `", stringify!($left_expr),
+                            "` to be equal to `", stringify!($right_expr), "`"
+                        ));
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero equality assertion macro from each macro name and
+/// integer type pair.
+macro_rules! gen_nz_assert_eq_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_assert_eq_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_assert_eq_macros!(
+    assert_eq_i8: i8,
+    assert_eq_i16: i16,
+    assert_eq_i32: i32,
+    assert_eq_i64: i64,
+    assert_eq_i128: i128,
+    assert_eq_isize: isize,
+    assert_eq_u8: u8,
+    assert_eq_u16: u16,
+    assert_eq_u32: u32,
+    assert_eq_u64: u64,
+    assert_eq_u128: u128,
+    assert_eq_usize: usize
+);
+
+/// Generates a non-zero fitting assertion macro for the specified integer
+/// type with a bound of another type that does not fit into it.
+macro_rules! gen_nz_assert_fits_macro {
+    ($macro_name:ident, $int_type:ident, $unfit_type:ident::$unfit_bound:ident) => {
+        #[doc = concat!("Asserts that a constant [`NonZero`][`core::num::NonZero`] value fits into [`prim@", stringify!($int_type), "`] at compile time.")]
+        #[doc = r""]
+        #[doc = concat!("It is the same check as [`nz::from_nonzero!`][`crate::from_nonzero`] with [`prim@", stringify!($int_type), "`] as the")]
+        /// target type, but no value is created and the expansion evaluates to
+        /// `()`, so it can be used for compile-time invariant checks (e.g. in
+        /// `const _: () = ...;` items). If the value does not fit into the
+        /// target type, a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### Assertion in constant item
+        /// ```rust
+        /// # use std::num::NonZero;
+        /// const LEN: NonZero<u128> = nz::u128!(100);
+        #[doc = concat!(" const _: () = nz::", stringify!($macro_name), "!(LEN);")]
+        /// ```
+        ///
+        /// #### Value that does not fit fails to compile
+        /// ```rust, compile_fail
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const UNFIT: NonZero<", stringify!($unfit_type), "> = NonZero::<", stringify!($unfit_type), ">::", stringify!($unfit_bound), ";")]
+        #[doc = concat!(" const _: () = nz::", stringify!($macro_name), "!(UNFIT);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($nz_expr:expr) => {
+                const {
+                    let value = $nz_expr.get();
+                    let converted = value as $int_type;
+                    // A non-zero value is negative if it is less than one, which
+                    // avoids comparing unsigned values against zero.
+                    if $crate::__private::same_type(converted as _, value) != value
+                        || (converted < 1) != (value < 1)
+                    {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: value of `", stringify!($nz_expr),
+                            "` does not fit into ", stringify!($int_type)
+                        ));
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero fitting assertion macro from each macro name, integer
+/// type and unfitting bound triple.
+macro_rules! gen_nz_assert_fits_macros {
+    ($($macro_name:ident: $int_type:ident => $unfit_type:ident::$unfit_bound:ident), *) => {
+        $(gen_nz_assert_fits_macro!($macro_name, $int_type, $unfit_type::$unfit_bound);)*
+    };
+}
+
+gen_nz_assert_fits_macros!(
+    assert_fits_i8: i8 => u128::MAX,
+    assert_fits_i16: i16 => u128::MAX,
+    assert_fits_i32: i32 => u128::MAX,
+    assert_fits_i64: i64 => u128::MAX,
+    assert_fits_i128: i128 => u128::MAX,
+    assert_fits_isize: isize => u128::MAX,
+    assert_fits_u8: u8 => i8::MIN,
+    assert_fits_u16: u16 => i8::MIN,
+    assert_fits_u32: u32 => i8::MIN,
+    assert_fits_u64: u64 => i8::MIN,
+    assert_fits_u128: u128 => i8::MIN,
+    assert_fits_usize: usize => i8::MIN
+);
@@ -0,0 +1,590 @@
+//! Macros that create non-zero bit masks and other bit patterns.
+
+/// Generates a non-zero single-bit mask macro for the specified unsigned
+/// integer type.
+macro_rules! gen_nz_bit_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates a single-bit [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] mask from a literal, constant")]
+        #[doc = concat!("value or expression that evaluates to the [`u32`] index of the bit.")]
+        #[doc = r""]
+        #[doc = concat!("The result is `1 << n`, which is always non-zero if `n` is less than [`", stringify!($int_type), "::BITS`],")]
+        /// therefore only the index is checked. If the shift overflows, a
+        /// [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literal
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const FLAG: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(5);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::BITS - 1);")]
+        /// # assert_eq!(1 << 5, FLAG.get());
+        #[doc = concat!(" # assert_eq!(1 << (", stringify!($int_type), "::BITS - 1), nz.get());")]
+        /// ```
+        ///
+        /// #### Overflowing shift fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::BITS);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($shift_expr:expr) => {
+                const {
+                    let shift: u32 = $shift_expr;
+                    if shift >= <$int_type>::BITS {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: shift `",
+                            stringify!($shift_expr), "` overflowed"
+                        ));
+                    }
+                    match core::num::NonZero::<$int_type>::new(1 << shift) {
+                        Some(non_zero) => non_zero,
+                        // the shift is in range, so the bit is set
+                        None => unreachable!(),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero single-bit mask macro from each macro name and unsigned
+/// integer type pair.
+macro_rules! gen_nz_bit_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_bit_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_bit_macros!(
+    bit_u8: u8,
+    bit_u16: u16,
+    bit_u32: u32,
+    bit_u64: u64,
+    bit_u128: u128,
+    bit_usize: usize
+);
+
+/// Generates a non-zero low-bit mask macro for the specified unsigned integer
+/// type.
+macro_rules! gen_nz_low_mask_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] mask of the lowest `k` bits from a literal,")]
+        #[doc = concat!("constant value or expression that evaluates to the [`u32`] width of the mask.")]
+        #[doc = r""]
+        #[doc = concat!("The result is `(1 << k) - 1` (without overflowing for [`", stringify!($int_type), "::BITS`]), which is")]
+        /// non-zero if `k` is non-zero. If the width is zero or it is greater
+        /// than the number of bits of the type, a [`panic`] will occur at
+        /// compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literal
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const MASK: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(4);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::BITS);")]
+        /// # assert_eq!(0b1111, MASK.get());
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::MAX, nz.get());")]
+        /// ```
+        ///
+        /// #### Zero width fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        ///
+        /// #### Too wide mask fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::BITS + 1);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($width_expr:expr) => {
+                const {
+                    let width: u32 = $width_expr;
+                    if width == 0 {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: expected non-zero mask width"
+                        ));
+                    }
+                    if width > <$int_type>::BITS {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: mask width `",
+                            stringify!($width_expr), "` exceeds ", stringify!($int_type), "::BITS"
+                        ));
+                    }
+                    match core::num::NonZero::<$int_type>::new(<$int_type>::MAX >> (<$int_type>::BITS - width)) {
+                        Some(non_zero) => non_zero,
+                        // the width is non-zero, so at least the lowest bit is set
+                        None => unreachable!(),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero low-bit mask macro from each macro name and unsigned
+/// integer type pair.
+macro_rules! gen_nz_low_mask_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_low_mask_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_low_mask_macros!(
+    low_mask_u8: u8,
+    low_mask_u16: u16,
+    low_mask_u32: u32,
+    low_mask_u64: u64,
+    low_mask_u128: u128,
+    low_mask_usize: usize
+);
+
+/// Generates a non-zero macro with required popcount for the specified integer
+/// type.
+macro_rules! gen_nz_with_popcount_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] from a literal, a constant value or")]
+        #[doc = concat!("expression that evaluates to [`prim@", stringify!($int_type), "`] with the specified number of set bits.")]
+        #[doc = r""]
+        /// The second argument is the required [`u32`] number of ones in the
+        /// binary representation of the value. If the value evaluates to zero
+        /// or the number of set bits differs, a [`panic`] will occur at compile
+        /// time with a distinct message for each case.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literal
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const MASK: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(0b0101_0100, 3);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(MASK.get() >> 2, MASK.get().count_ones());")]
+        /// # assert_eq!(0b0101_0100, MASK.get());
+        /// # assert_eq!(0b0001_0101, nz.get());
+        /// ```
+        ///
+        /// #### Mismatched number of set bits fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0b0110, 3);")]
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0, 0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr, $count_expr:expr) => {
+                const {
+                    let value = $crate::$int_type!(
+                        $int_expr,
+                        concat!(
+                            "nz::", stringify!($macro_name), "!: expected non-zero ",
+                            stringify!($int_type), " value"
+                        )
+                    );
+                    let count: u32 = $count_expr;
+                    if value.get().count_ones() != count {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: expected `", stringify!($count_expr),
+                            "` set bits in `", stringify!($int_expr), "`"
+                        ));
+                    }
+                    value
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero macro with required popcount from each macro name and
+/// integer type pair.
+macro_rules! gen_nz_with_popcount_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_with_popcount_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_with_popcount_macros!(
+    with_popcount_i8: i8,
+    with_popcount_i16: i16,
+    with_popcount_i32: i32,
+    with_popcount_i64: i64,
+    with_popcount_i128: i128,
+    with_popcount_isize: isize,
+    with_popcount_u8: u8,
+    with_popcount_u16: u16,
+    with_popcount_u32: u32,
+    with_popcount_u64: u64,
+    with_popcount_u128: u128,
+    with_popcount_usize: usize
+);
+
+/// Generates a non-zero bit reordering macro for the specified integer type.
+macro_rules! gen_nz_bit_order_macro {
+    ($macro_name:ident, $int_type:ident, $fn_name:ident, $description:literal, ($example_arg:expr) -> $example:expr) => {
+        #[doc = concat!("Computes the ", $description, " a literal, constant value or expression that")]
+        #[doc = concat!("evaluates to non-zero [`prim@", stringify!($int_type), "`] as a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        #[doc = concat!("The result is [`", stringify!($int_type), "::", stringify!($fn_name), "`] of the value, which has the same")]
+        /// number of set bits, therefore only the argument is checked. If the
+        /// argument evaluates to zero, a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literal
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(", $example_arg, ");")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(NZ.get());")]
+        #[doc = concat!(" # assert_eq!(", $example, ", NZ.get());")]
+        #[doc = concat!(" # assert_eq!(", $example_arg, ", nz.get());")]
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {
+                const {
+                    let value = $crate::__nz_operand!($macro_name, $int_type, $int_expr);
+                    match core::num::NonZero::<$int_type>::new(value.get().$fn_name()) {
+                        Some(non_zero) => non_zero,
+                        // reordering keeps the set bits of the non-zero value
+                        None => unreachable!(),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero byte swapping macro from each macro name and integer
+/// type pair.
+macro_rules! gen_nz_swap_bytes_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(
+            gen_nz_bit_order_macro!(
+                $macro_name, $int_type, swap_bytes, "byte-swapped value of",
+                ("1") -> concat!("(1 as ", stringify!($int_type), ").swap_bytes()")
+            );
+        )*
+    };
+}
+
+gen_nz_swap_bytes_macros!(
+    swap_bytes_i8: i8,
+    swap_bytes_i16: i16,
+    swap_bytes_i32: i32,
+    swap_bytes_i64: i64,
+    swap_bytes_i128: i128,
+    swap_bytes_isize: isize,
+    swap_bytes_u8: u8,
+    swap_bytes_u16: u16,
+    swap_bytes_u32: u32,
+    swap_bytes_u64: u64,
+    swap_bytes_u128: u128,
+    swap_bytes_usize: usize
+);
+
+/// Generates a macro that creates a non-zero value with its number of leading
+/// zeros for the specified integer type.
+macro_rules! gen_nz_with_leading_zeros_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates a tuple of a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] and its number of leading zeros from")]
+        #[doc = concat!("a literal, a constant value or expression that evaluates to [`prim@", stringify!($int_type), "`].")]
+        #[doc = r""]
+        /// The second element of the tuple is the [`u32`] number of leading
+        #[doc = concat!("zeros in the binary representation of the value, which is less than [`", stringify!($int_type), "::BITS`],")]
+        /// because the value is non-zero. Both are computed once at compile
+        /// time. If the argument evaluates to zero, a [`panic`] will occur at
+        /// compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literal
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const FIELD: (NonZero<", stringify!($int_type), ">, u32) = nz::", stringify!($macro_name), "!(0b0001_0000);")]
+        #[doc = concat!(" let (nz, zeros) = nz::", stringify!($macro_name), "!(1);")]
+        /// # assert_eq!(0b0001_0000, FIELD.0.get());
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::BITS - 5, FIELD.1);")]
+        /// # assert_eq!(1, nz.get());
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::BITS - 1, zeros);")]
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {
+                const {
+                    let non_zero = $crate::$int_type!(
+                        $int_expr,
+                        concat!(
+                            "nz::", stringify!($macro_name), "!: expected non-zero ",
+                            stringify!($int_type), " value"
+                        )
+                    );
+                    (non_zero, non_zero.get().leading_zeros())
+                }
+            };
+        }
+    };
+}
+
+/// Generates a macro that creates a non-zero value with its number of leading
+/// zeros from each macro name and integer type pair.
+macro_rules! gen_nz_with_leading_zeros_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_with_leading_zeros_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_with_leading_zeros_macros!(
+    with_leading_zeros_i8: i8,
+    with_leading_zeros_i16: i16,
+    with_leading_zeros_i32: i32,
+    with_leading_zeros_i64: i64,
+    with_leading_zeros_i128: i128,
+    with_leading_zeros_isize: isize,
+    with_leading_zeros_u8: u8,
+    with_leading_zeros_u16: u16,
+    with_leading_zeros_u32: u32,
+    with_leading_zeros_u64: u64,
+    with_leading_zeros_u128: u128,
+    with_leading_zeros_usize: usize
+);
+
+/// Generates a non-zero bit rotation macro for the specified integer type.
+macro_rules! gen_nz_rotate_macro {
+    ($macro_name:ident, $int_type:ident, $fn_name:ident, $direction:literal, $example:literal) => {
+        #[doc = concat!("Rotates the bits of a literal, constant value or expression that evaluates to")]
+        #[doc = concat!("non-zero [`prim@", stringify!($int_type), "`] to the ", $direction, " resulting in a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        #[doc = concat!("The second argument is the [`u32`] number of bits to rotate by like in [`", stringify!($int_type), "::", stringify!($fn_name), "`].")]
+        /// Rotation keeps the set bits of the value, therefore only the first
+        /// argument is checked. If it evaluates to zero, a [`panic`] will occur
+        /// at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literals
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(", $example, ", 1);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(NZ.get(), ", stringify!($int_type), "::BITS - 1);")]
+        #[doc = concat!(" # assert_eq!((", $example, " as ", stringify!($int_type), ").", stringify!($fn_name), "(1), NZ.get());")]
+        #[doc = concat!(" # assert_eq!(", $example, ", nz.get());")]
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0, 1);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr, $bits_expr:expr) => {
+                const {
+                    let value = $crate::__nz_operand!($macro_name, $int_type, $int_expr);
+                    let bits: u32 = $bits_expr;
+                    match core::num::NonZero::<$int_type>::new(value.get().$fn_name(bits)) {
+                        Some(non_zero) => non_zero,
+                        // rotation keeps the set bits of the non-zero value
+                        None => unreachable!(),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates non-zero left and right bit rotation macros from each macro name
+/// pair and integer type.
+macro_rules! gen_nz_rotate_macros {
+    ($($left_macro_name:ident, $right_macro_name:ident: $int_type:ident), *) => {
+        $(
+            gen_nz_rotate_macro!($left_macro_name, $int_type, rotate_left, "left", "0b0110");
+            gen_nz_rotate_macro!($right_macro_name, $int_type, rotate_right, "right", "0b0110");
+        )*
+    };
+}
+
+gen_nz_rotate_macros!(
+    rotate_left_i8, rotate_right_i8: i8,
+    rotate_left_i16, rotate_right_i16: i16,
+    rotate_left_i32, rotate_right_i32: i32,
+    rotate_left_i64, rotate_right_i64: i64,
+    rotate_left_i128, rotate_right_i128: i128,
+    rotate_left_isize, rotate_right_isize: isize,
+    rotate_left_u8, rotate_right_u8: u8,
+    rotate_left_u16, rotate_right_u16: u16,
+    rotate_left_u32, rotate_right_u32: u32,
+    rotate_left_u64, rotate_right_u64: u64,
+    rotate_left_u128, rotate_right_u128: u128,
+    rotate_left_usize, rotate_right_usize: usize
+);
+
+/// Generates a non-zero bit reversing macro from each macro name and integer
+/// type pair.
+macro_rules! gen_nz_reverse_bits_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(
+            gen_nz_bit_order_macro!(
+                $macro_name, $int_type, reverse_bits, "value with reversed bit order of",
+                ("1") -> concat!("1 << (", stringify!($int_type), "::BITS - 1)")
+            );
+        )*
+    };
+}
+
+gen_nz_reverse_bits_macros!(
+    reverse_bits_i8: i8,
+    reverse_bits_i16: i16,
+    reverse_bits_i32: i32,
+    reverse_bits_i64: i64,
+    reverse_bits_i128: i128,
+    reverse_bits_isize: isize,
+    reverse_bits_u8: u8,
+    reverse_bits_u16: u16,
+    reverse_bits_u32: u32,
+    reverse_bits_u64: u64,
+    reverse_bits_u128: u128,
+    reverse_bits_usize: usize
+);
+
+/// Generates a non-zero power of two macro for the specified unsigned integer
+/// type.
+macro_rules! gen_nz_pow2_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] from a literal, a constant value or")]
+        #[doc = concat!("expression that evaluates to a power of two [`prim@", stringify!($int_type), "`].")]
+        #[doc = r""]
+        /// If the argument evaluates to zero or it is not a power of two, a
+        /// [`panic`] will occur at compile time with a distinct message for
+        /// each case.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant expression
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const CAPACITY: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(64);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(CAPACITY.get() / 2);")]
+        /// # assert_eq!(64, CAPACITY.get());
+        /// # assert_eq!(32, nz.get());
+        /// ```
+        ///
+        /// #### Value that is not a power of two fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(48);")]
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {
+                const {
+                    let value = $crate::$int_type!(
+                        $int_expr,
+                        concat!(
+                            "nz::", stringify!($macro_name), "!: expected non-zero ",
+                            stringify!($int_type), " value"
+                        )
+                    );
+                    if !value.is_power_of_two() {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: `", stringify!($int_expr),
+                            "` is not a power of two"
+                        ));
+                    }
+                    value
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero power of two macro from each macro name and unsigned
+/// integer type pair.
+macro_rules! gen_nz_pow2_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_pow2_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_pow2_macros!(
+    pow2_u8: u8,
+    pow2_u16: u16,
+    pow2_u32: u32,
+    pow2_u64: u64,
+    pow2_u128: u128,
+    pow2_usize: usize
+);
+
+/// Generates a non-zero byte broadcasting macro for the specified unsigned
+/// integer type.
+macro_rules! gen_nz_repeat_byte_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] by repeating a literal, constant value or")]
+        /// expression that evaluates to non-zero [`prim@u8`] in every byte at
+        /// compile time.
+        ///
+        #[doc = concat!("The result is `", stringify!($int_type), "::MAX / 0xff * byte`, which is non-zero whenever the byte is,")]
+        /// therefore only the argument is checked. If the argument evaluates to
+        /// zero, a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literal
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const LOW_BITS: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(0x01);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(0x5a);")]
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::MAX / 0xff, LOW_BITS.get());")]
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::from_ne_bytes([0x5a; core::mem::size_of::<", stringify!($int_type), ">()]), nz.get());")]
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($byte_expr:expr) => {
+                const {
+                    let byte = $crate::__nz_operand!($macro_name, u8, $byte_expr);
+                    match core::num::NonZero::<$int_type>::new(<$int_type>::MAX / 0xff * byte.get() as $int_type) {
+                        Some(non_zero) => non_zero,
+                        // every byte of the result is the non-zero byte
+                        None => unreachable!(),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero byte broadcasting macro from each macro name and
+/// unsigned integer type pair.
+macro_rules! gen_nz_repeat_byte_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_repeat_byte_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_repeat_byte_macros!(
+    repeat_byte_u16: u16,
+    repeat_byte_u32: u32,
+    repeat_byte_u64: u64,
+    repeat_byte_u128: u128,
+    repeat_byte_usize: usize
+);
@@ -0,0 +1,144 @@
+//! Macros that declare range-restricted [`NonZero`][`core::num::NonZero`]
+//! wrapper types.
+
+/// Generates a bounded non-zero wrapper type macro for the specified integer
+/// type.
+macro_rules! gen_nz_bounded_macro {
+    ($d:tt $macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Declares a wrapper type of [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] whose value is restricted to an")]
+        /// inclusive range.
+        ///
+        /// The arguments are the name of the type, which can be preceded by
+        /// attributes and a visibility, and the constant minimum and maximum of
+        /// the range. The bounds are checked when the type is declared, so if
+        /// the minimum is greater than the maximum or the range only contains
+        /// zero, a [`panic`] will occur at compile time.
+        ///
+        /// The declared tuple struct has the following items:
+        ///
+        /// * `MIN` and `MAX`: the bounds of the range
+        /// * `new`: a `const fn` that returns [`None`] if the value is zero or out of range
+        /// * `new_const`: a `const fn` that panics if the value is zero or out of
+        ///   range, which happens at compile time if it is called in a constant
+        /// * `get`: a `const fn` that returns the wrapped non-zero value
+        ///
+        #[doc = concat!("The type can be converted to [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] with [`From`].")]
+        ///
+        /// # Examples
+        ///
+        /// #### Wrapper type
+        /// ```rust
+        #[doc = concat!(" nz::", stringify!($macro_name), "!(pub Level, 1, 100);")]
+        ///
+        /// const DEFAULT: Level = Level::new_const(10);
+        /// let level = Level::new(42);
+        /// let out_of_range = Level::new(101);
+        /// # assert_eq!(10, DEFAULT.get().get());
+        /// # assert_eq!(Some(42), level.map(|level| level.get().get()));
+        /// # assert!(out_of_range.is_none());
+        /// # assert!(Level::new(0).is_none());
+        /// ```
+        ///
+        /// #### Out of range constant fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" nz::", stringify!($macro_name), "!(Level, 1, 100);")]
+        ///
+        /// const LEVEL: Level = Level::new_const(101);
+        /// ```
+        ///
+        /// #### Minimum greater than maximum fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" nz::", stringify!($macro_name), "!(Level, 100, 1);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            (
+                $d(#[$d attr:meta])* $d vis:vis $d type_name:ident,
+                $d min_expr:expr, $d max_expr:expr $d(,)?
+            ) => {
+                $d(#[$d attr])*
+                #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+                $d vis struct $d type_name(core::num::NonZero<$int_type>);
+
+                const _: () = {
+                    if $d type_name::MIN > $d type_name::MAX {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: expected minimum `",
+                            stringify!($d min_expr), "` to be less than or equal to maximum `",
+                            stringify!($d max_expr), "`"
+                        ));
+                    }
+                    if $d type_name::MIN == 0 && $d type_name::MAX == 0 {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: expected range with non-zero value"
+                        ));
+                    }
+                };
+
+                impl $d type_name {
+                    /// The minimum value of the range.
+                    pub const MIN: $int_type = $d min_expr;
+                    /// The maximum value of the range.
+                    pub const MAX: $int_type = $d max_expr;
+
+                    /// Creates a new value if it is non-zero and in range.
+                    pub const fn new(value: $int_type) -> Option<Self> {
+                        if value < Self::MIN || value > Self::MAX {
+                            return None;
+                        }
+                        match core::num::NonZero::<$int_type>::new(value) {
+                            Some(non_zero) => Some(Self(non_zero)),
+                            None => None,
+                        }
+                    }
+
+                    /// Creates a new value, panicking if it is zero or out of range.
+                    pub const fn new_const(value: $int_type) -> Self {
+                        match Self::new(value) {
+                            Some(bounded) => bounded,
+                            None => panic!(concat!(
+                                "nz::", stringify!($macro_name), "!: expected non-zero value in the range of `",
+                                stringify!($d type_name), "`"
+                            )),
+                        }
+                    }
+
+                    /// Returns the wrapped non-zero value.
+                    pub const fn get(self) -> core::num::NonZero<$int_type> {
+                        self.0
+                    }
+                }
+
+                impl core::convert::From<$d type_name> for core::num::NonZero<$int_type> {
+                    fn from(bounded: $d type_name) -> Self {
+                        bounded.0
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a bounded non-zero wrapper type macro from each macro name and
+/// integer type pair.
+macro_rules! gen_nz_bounded_macros {
+    ($d:tt $($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_bounded_macro!($d $macro_name, $int_type);)*
+    };
+}
+
+gen_nz_bounded_macros!(
+    $
+    bounded_i8: i8,
+    bounded_i16: i16,
+    bounded_i32: i32,
+    bounded_i64: i64,
+    bounded_i128: i128,
+    bounded_isize: isize,
+    bounded_u8: u8,
+    bounded_u16: u16,
+    bounded_u32: u32,
+    bounded_u64: u64,
+    bounded_u128: u128,
+    bounded_usize: usize
+);
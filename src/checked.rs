@@ -0,0 +1,397 @@
+//! Macros that perform checked arithmetic operations resulting in an
+//! [`Option`] of [`NonZero`][`core::num::NonZero`] at compile time.
+
+/// Generates a checked non-zero arithmetic macro for the specified integer
+/// type.
+macro_rules! gen_nz_checked_macro {
+    (
+        $macro_name:ident, $int_type:ident, $op_name:literal, $none_cond:literal,
+        |$a:ident, $b:ident| $op:expr,
+        ($some_a:expr, $some_b:expr) -> $some:expr,
+        $(($none_a:expr, $none_b:expr)),+
+    ) => {
+        #[doc = concat!("Computes the checked ", $op_name, " of two literals, constant values or expressions")]
+        #[doc = concat!("that evaluate to non-zero [`prim@", stringify!($int_type), "`] as an")]
+        #[doc = concat!("[`Option<NonZero<", stringify!($int_type), ">>`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        #[doc = concat!("[`None`] is returned if ", $none_cond, ", otherwise the result is")]
+        /// wrapped in [`Some`]. If any of the arguments evaluates to zero, a
+        /// [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant expressions
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: Option<NonZero<", stringify!($int_type), ">> = nz::", stringify!($macro_name), "!(", $some_a, ", ", $some_b, ");")]
+        #[doc = concat!(" # assert_eq!(Some(", $some, "), NZ.map(NonZero::get));")]
+        $(
+            #[doc = concat!(" let none = nz::", stringify!($macro_name), "!(", $none_a, ", ", $none_b, ");")]
+            /// # assert_eq!(None, none);
+        )+
+        /// ```
+        ///
+        /// #### Zero argument fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(", $some_a, ", 0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($a_expr:expr, $b_expr:expr) => {
+                const {
+                    let $a = $crate::__nz_operand!($macro_name, $int_type, $a_expr);
+                    let $b = $crate::__nz_operand!($macro_name, $int_type, $b_expr);
+                    $op
+                }
+            };
+        }
+    };
+}
+
+/// Generates a checked non-zero addition macro from each macro name and
+/// unsigned integer type pair.
+macro_rules! gen_nz_checked_add_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(
+            gen_nz_checked_macro!(
+                $macro_name, $int_type, "addition", "the addition overflows",
+                |a, b| a.checked_add(b.get()),
+                ("1", "2") -> "3",
+                (concat!(stringify!($int_type), "::MAX"), "1")
+            );
+        )*
+    };
+}
+
+gen_nz_checked_add_macros!(
+    checked_add_u8: u8,
+    checked_add_u16: u16,
+    checked_add_u32: u32,
+    checked_add_u64: u64,
+    checked_add_u128: u128,
+    checked_add_usize: usize
+);
+
+/// Generates a checked non-zero subtraction macro from each macro name and
+/// integer type pair.
+macro_rules! gen_nz_checked_sub_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(
+            gen_nz_checked_macro!(
+                $macro_name, $int_type, "subtraction",
+                "the subtraction overflows or the difference is zero",
+                |a, b| match a.get().checked_sub(b.get()) {
+                    Some(difference) => core::num::NonZero::<$int_type>::new(difference),
+                    None => None,
+                },
+                ("5", "3") -> "2",
+                ("3", "3"),
+                (concat!(stringify!($int_type), "::MIN + 1"), "2")
+            );
+        )*
+    };
+}
+
+gen_nz_checked_sub_macros!(
+    checked_sub_i8: i8,
+    checked_sub_i16: i16,
+    checked_sub_i32: i32,
+    checked_sub_i64: i64,
+    checked_sub_i128: i128,
+    checked_sub_isize: isize,
+    checked_sub_u8: u8,
+    checked_sub_u16: u16,
+    checked_sub_u32: u32,
+    checked_sub_u64: u64,
+    checked_sub_u128: u128,
+    checked_sub_usize: usize
+);
+
+/// Generates a checked non-zero multiplication macro from each macro name and
+/// integer type pair.
+macro_rules! gen_nz_checked_mul_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(
+            gen_nz_checked_macro!(
+                $macro_name, $int_type, "multiplication", "the multiplication overflows",
+                |a, b| a.checked_mul(b),
+                ("3", "5") -> "15",
+                (concat!(stringify!($int_type), "::MAX"), "2")
+            );
+        )*
+    };
+}
+
+gen_nz_checked_mul_macros!(
+    checked_mul_i8: i8,
+    checked_mul_i16: i16,
+    checked_mul_i32: i32,
+    checked_mul_i64: i64,
+    checked_mul_i128: i128,
+    checked_mul_isize: isize,
+    checked_mul_u8: u8,
+    checked_mul_u16: u16,
+    checked_mul_u32: u32,
+    checked_mul_u64: u64,
+    checked_mul_u128: u128,
+    checked_mul_usize: usize
+);
+
+/// Generates a checked non-zero exponentiation macro for the specified integer
+/// type.
+macro_rules! gen_nz_checked_pow_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Computes the checked exponentiation of an expression that evaluates to [`prim@", stringify!($int_type), "`]")]
+        #[doc = concat!("to the power of a [`prim@u32`] exponent as an [`Option<NonZero<", stringify!($int_type), ">>`][`core::num::NonZero`].")]
+        #[doc = r""]
+        /// [`None`] is returned if the base is zero or the exponentiation
+        /// overflows, otherwise the result is wrapped in [`Some`]. Any power of
+        /// a non-zero value is non-zero, therefore only the base is checked.
+        #[doc = concat!("Like [`nz::try_", stringify!($int_type), "!`][`crate::try_", stringify!($int_type), "`], the arguments are not required to be constant")]
+        /// expressions, therefore it can be used in both constant and
+        /// non-constant contexts.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant expressions
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: Option<NonZero<", stringify!($int_type), ">> = nz::", stringify!($macro_name), "!(2, 6);")]
+        #[doc = concat!(" const NONE: Option<NonZero<", stringify!($int_type), ">> = nz::", stringify!($macro_name), "!(2, ", stringify!($int_type), "::BITS);")]
+        /// # assert_eq!(Some(64), NZ.map(NonZero::get));
+        /// # assert_eq!(None, NONE);
+        /// ```
+        ///
+        /// #### From non-constant expressions
+        /// ```rust
+        #[doc = concat!(" fn parse(s: &str) -> ", stringify!($int_type), " { s.parse().unwrap_or(0) }")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(parse(\"3\"), parse(\"4\") as u32);")]
+        #[doc = concat!(" let zero = nz::", stringify!($macro_name), "!(parse(\"zero\"), 2);")]
+        /// # assert_eq!(Some(81), nz.map(|nz| nz.get()));
+        /// # assert_eq!(None, zero);
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($base_expr:expr, $exp_expr:expr) => {
+                match core::num::NonZero::<$int_type>::new($base_expr) {
+                    Some(base) => base.checked_pow($exp_expr),
+                    None => None,
+                }
+            };
+        }
+    };
+}
+
+/// Generates a checked non-zero exponentiation macro from each macro name and
+/// integer type pair.
+macro_rules! gen_nz_checked_pow_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_checked_pow_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_checked_pow_macros!(
+    checked_pow_i8: i8,
+    checked_pow_i16: i16,
+    checked_pow_i32: i32,
+    checked_pow_i64: i64,
+    checked_pow_i128: i128,
+    checked_pow_isize: isize,
+    checked_pow_u8: u8,
+    checked_pow_u16: u16,
+    checked_pow_u32: u32,
+    checked_pow_u64: u64,
+    checked_pow_u128: u128,
+    checked_pow_usize: usize
+);
+
+/// Generates a checked non-zero next multiple macro for the specified unsigned
+/// integer type.
+macro_rules! gen_nz_checked_next_multiple_of_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Computes the checked next multiple of two expressions that evaluate to [`prim@", stringify!($int_type), "`] as an")]
+        #[doc = concat!("[`Option<NonZero<", stringify!($int_type), ">>`][`core::num::NonZero`].")]
+        #[doc = r""]
+        /// [`None`] is returned if any of the arguments is zero or the next
+        /// multiple overflows, otherwise the result is wrapped in [`Some`]. The
+        /// next multiple is greater than or equal to the non-zero value,
+        /// therefore only the arguments are checked.
+        #[doc = concat!("Like [`nz::try_", stringify!($int_type), "!`][`crate::try_", stringify!($int_type), "`], the arguments are not required to be constant")]
+        /// expressions, therefore it can be used in both constant and
+        /// non-constant contexts.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant expressions
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: Option<NonZero<", stringify!($int_type), ">> = nz::", stringify!($macro_name), "!(10, 8);")]
+        #[doc = concat!(" const NONE: Option<NonZero<", stringify!($int_type), ">> = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX, 2);")]
+        /// # assert_eq!(Some(16), NZ.map(NonZero::get));
+        /// # assert_eq!(None, NONE);
+        /// ```
+        ///
+        /// #### From non-constant expressions
+        /// ```rust
+        #[doc = concat!(" fn parse(s: &str) -> ", stringify!($int_type), " { s.parse().unwrap_or(0) }")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(parse(\"100\"), parse(\"64\"));")]
+        #[doc = concat!(" let zero = nz::", stringify!($macro_name), "!(parse(\"100\"), parse(\"zero\"));")]
+        /// # assert_eq!(Some(128), nz.map(|nz| nz.get()));
+        /// # assert_eq!(None, zero);
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr, $rhs_expr:expr) => {
+                match (
+                    core::num::NonZero::<$int_type>::new($int_expr),
+                    core::num::NonZero::<$int_type>::new($rhs_expr),
+                ) {
+                    (Some(value), Some(rhs)) => match value.get().checked_next_multiple_of(rhs.get()) {
+                        Some(multiple) => core::num::NonZero::<$int_type>::new(multiple),
+                        None => None,
+                    },
+                    _ => None,
+                }
+            };
+        }
+    };
+}
+
+/// Generates a checked non-zero next multiple macro from each macro name and
+/// unsigned integer type pair.
+macro_rules! gen_nz_checked_next_multiple_of_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_checked_next_multiple_of_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_checked_next_multiple_of_macros!(
+    checked_next_multiple_of_u8: u8,
+    checked_next_multiple_of_u16: u16,
+    checked_next_multiple_of_u32: u32,
+    checked_next_multiple_of_u64: u64,
+    checked_next_multiple_of_u128: u128,
+    checked_next_multiple_of_usize: usize
+);
+
+/// Generates a checked non-zero left shift macro for the specified unsigned
+/// integer type.
+macro_rules! gen_nz_checked_shl_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Computes the checked left shift of a literal, constant value or expression that evaluates")]
+        #[doc = concat!("to non-zero [`prim@", stringify!($int_type), "`] by a constant [`prim@u32`] amount as an")]
+        #[doc = concat!("[`Option<NonZero<", stringify!($int_type), ">>`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        #[doc = concat!("[`None`] is returned if the amount is not less than [`", stringify!($int_type), "::BITS`] or every set bit")]
+        /// is shifted out, so the result would be zero, otherwise the result is
+        /// wrapped in [`Some`]. If the value evaluates to zero, a [`panic`] will
+        /// occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant expressions
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: Option<NonZero<", stringify!($int_type), ">> = nz::", stringify!($macro_name), "!(3, 4);")]
+        #[doc = concat!(" let shifted_out = nz::", stringify!($macro_name), "!(1 << (", stringify!($int_type), "::BITS - 1), 1);")]
+        #[doc = concat!(" let out_of_range = nz::", stringify!($macro_name), "!(1, ", stringify!($int_type), "::BITS);")]
+        /// # assert_eq!(Some(48), NZ.map(NonZero::get));
+        /// # assert_eq!(None, shifted_out);
+        /// # assert_eq!(None, out_of_range);
+        /// ```
+        ///
+        /// #### Zero value fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0, 2);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr, $shift_expr:expr) => {
+                const {
+                    let value = $crate::__nz_operand!($macro_name, $int_type, $int_expr);
+                    let shift: u32 = $shift_expr;
+                    match value.get().checked_shl(shift) {
+                        Some(shifted) => core::num::NonZero::<$int_type>::new(shifted),
+                        None => None,
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a checked non-zero left shift macro from each macro name and
+/// unsigned integer type pair.
+macro_rules! gen_nz_checked_shl_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_checked_shl_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_checked_shl_macros!(
+    checked_shl_u8: u8,
+    checked_shl_u16: u16,
+    checked_shl_u32: u32,
+    checked_shl_u64: u64,
+    checked_shl_u128: u128,
+    checked_shl_usize: usize
+);
+
+/// Generates a checked non-zero division macro for the specified unsigned
+/// integer type.
+macro_rules! gen_nz_checked_div_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Computes the checked division of a literal, constant value or expression that evaluates to")]
+        #[doc = concat!("[`prim@", stringify!($int_type), "`] by a non-zero divisor as an")]
+        #[doc = concat!("[`Option<NonZero<", stringify!($int_type), ">>`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// The divisor is checked, so the division cannot panic, while the
+        /// dividend may be zero. [`None`] is returned if the quotient is zero
+        /// (i.e. the dividend is less than the divisor), otherwise the result is
+        /// wrapped in [`Some`]. If the divisor evaluates to zero, a [`panic`]
+        /// will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant expressions
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: Option<NonZero<", stringify!($int_type), ">> = nz::", stringify!($macro_name), "!(17, 5);")]
+        #[doc = concat!(" let none = nz::", stringify!($macro_name), "!(3, 5);")]
+        /// # assert_eq!(Some(3), NZ.map(NonZero::get));
+        /// # assert_eq!(None, none);
+        /// ```
+        ///
+        /// #### Zero divisor fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(17, 0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($a_expr:expr, $b_expr:expr) => {
+                const {
+                    let a: $int_type = $a_expr;
+                    let b = $crate::__nz_operand!($macro_name, $int_type, $b_expr);
+                    core::num::NonZero::<$int_type>::new(a / b.get())
+                }
+            };
+        }
+    };
+}
+
+/// Generates a checked non-zero division macro from each macro name and
+/// unsigned integer type pair.
+macro_rules! gen_nz_checked_div_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_checked_div_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_checked_div_macros!(
+    checked_div_u8: u8,
+    checked_div_u16: u16,
+    checked_div_u32: u32,
+    checked_div_u64: u64,
+    checked_div_u128: u128,
+    checked_div_usize: usize
+);
@@ -0,0 +1,412 @@
+//! Macros that compare [`NonZero`][`core::num::NonZero`] values at compile
+//! time.
+
+/// Generates a non-zero minimum or maximum macro for the specified integer
+/// type.
+macro_rules! gen_nz_min_max_macro {
+    ($d:tt $macro_name:ident, $int_type:ident, $op:tt, $kind:literal, $bound:ident, $example:literal) => {
+        #[doc = concat!("Returns the ", $kind, " of two or more literals, constant values or expressions")]
+        #[doc = concat!("that evaluate to non-zero [`prim@", stringify!($int_type), "`] as a")]
+        #[doc = concat!("[`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// Every argument is checked and if any of them evaluates to zero, a
+        /// [`panic`] will occur at compile time. The result is one of the
+        /// arguments, so it is not checked again. A trailing comma is accepted,
+        /// but calling the macro with a single argument fails to compile.
+        ///
+        #[doc = concat!("Without arguments the macro returns the ", $kind, " value of [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`],")]
+        #[doc = concat!("which is [`NonZero::<", stringify!($int_type), ">::", stringify!($bound), "`][`core::num::NonZero::", stringify!($bound), "`].")]
+        /// The maximum is always the type's `MAX`, but the minimum depends on the
+        /// signedness: it is `1` for unsigned integer types and the type's `MIN`
+        /// for signed integer types.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literals
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(8, 3, 27);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(NZ.get(), 5,);")]
+        #[doc = concat!(" # assert_eq!(", $example, ", NZ.get());")]
+        #[doc = concat!(" # assert_eq!(", $example, ", nz.get());")]
+        /// ```
+        ///
+        /// #### Without arguments
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!();")]
+        #[doc = concat!(" # assert_eq!(NonZero::<", stringify!($int_type), ">::", stringify!($bound), ", NZ);")]
+        /// ```
+        ///
+        /// #### Zero argument fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(8, 0, 27);")]
+        /// ```
+        ///
+        /// #### Single argument fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(8);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($d first_expr:expr $d(, $d rest_expr:expr)+ $d(,)?) => {
+                const {
+                    let mut result = $crate::__nz_operand!($macro_name, $int_type, $d first_expr);
+                    $d(
+                        let operand = $crate::__nz_operand!($macro_name, $int_type, $d rest_expr);
+                        if operand.get() $op result.get() {
+                            result = operand;
+                        }
+                    )+
+                    result
+                }
+            };
+            ($d int_expr:expr $d(,)?) => {
+                compile_error!(concat!(
+                    "nz::", stringify!($macro_name), "!: expected at least two arguments"
+                ))
+            };
+            () => {
+                core::num::NonZero::<$int_type>::$bound
+            };
+        }
+    };
+}
+
+/// Generates a non-zero minimum and maximum macro from each macro name pair
+/// and integer type.
+macro_rules! gen_nz_min_max_macros {
+    ($d:tt $($min_name:ident, $max_name:ident: $int_type:ident), *) => {
+        $(
+            gen_nz_min_max_macro!($d $min_name, $int_type, <, "minimum", MIN, "3");
+            gen_nz_min_max_macro!($d $max_name, $int_type, >, "maximum", MAX, "27");
+        )*
+    };
+}
+
+gen_nz_min_max_macros!(
+    $
+    min_i8, max_i8: i8,
+    min_i16, max_i16: i16,
+    min_i32, max_i32: i32,
+    min_i64, max_i64: i64,
+    min_i128, max_i128: i128,
+    min_isize, max_isize: isize,
+    min_u8, max_u8: u8,
+    min_u16, max_u16: u16,
+    min_u32, max_u32: u32,
+    min_u64, max_u64: u64,
+    min_u128, max_u128: u128,
+    min_usize, max_usize: usize
+);
+
+/// Generates a non-zero clamp macro for the specified integer type.
+macro_rules! gen_nz_clamp_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Restricts a literal, constant value or expression that evaluates to non-zero")]
+        #[doc = concat!("[`prim@", stringify!($int_type), "`] to a non-zero interval resulting in a")]
+        #[doc = concat!("[`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        #[doc = concat!("The arguments are the value, the minimum and the maximum like in [`", stringify!($int_type), "::clamp`].")]
+        /// If any of the arguments evaluates to zero or the minimum is greater
+        /// than the maximum, a [`panic`] will occur at compile time. The result
+        /// is one of the arguments, so it is not checked again.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literals
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(100, 1, 64);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(2, 4, NZ.get());")]
+        /// # assert_eq!(64, NZ.get());
+        /// # assert_eq!(4, nz.get());
+        /// ```
+        ///
+        /// #### Zero argument fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0, 1, 64);")]
+        /// ```
+        ///
+        /// #### Minimum greater than maximum fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(8, 64, 1);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr, $min_expr:expr, $max_expr:expr) => {
+                const {
+                    let value = $crate::__nz_operand!($macro_name, $int_type, $int_expr);
+                    let min = $crate::__nz_operand!($macro_name, $int_type, $min_expr);
+                    let max = $crate::__nz_operand!($macro_name, $int_type, $max_expr);
+                    if min.get() > max.get() {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: expected minimum `",
+                            stringify!($min_expr), "` to be less than or equal to maximum `",
+                            stringify!($max_expr), "`"
+                        ));
+                    }
+                    if value.get() < min.get() {
+                        min
+                    } else if value.get() > max.get() {
+                        max
+                    } else {
+                        value
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero clamp macro from each macro name and integer type
+/// pair.
+macro_rules! gen_nz_clamp_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_clamp_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_clamp_macros!(
+    clamp_i8: i8,
+    clamp_i16: i16,
+    clamp_i32: i32,
+    clamp_i64: i64,
+    clamp_i128: i128,
+    clamp_isize: isize,
+    clamp_u8: u8,
+    clamp_u16: u16,
+    clamp_u32: u32,
+    clamp_u64: u64,
+    clamp_u128: u128,
+    clamp_usize: usize
+);
+
+/// Generates a range restricted non-zero macro for the specified integer type.
+macro_rules! gen_nz_in_range_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] from a literal, a constant value")]
+        #[doc = concat!("or expression that evaluates to [`prim@", stringify!($int_type), "`] within an inclusive range.")]
+        #[doc = r""]
+        #[doc = concat!("The first argument is a constant [`RangeInclusive<", stringify!($int_type), ">`][`core::ops::RangeInclusive`] (e.g. `1..=127`).")]
+        /// If the value evaluates to zero or it is not within the range, a
+        /// [`panic`] will occur at compile time with a distinct message for
+        /// each case.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literal
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(1..=127, 0x10);")]
+        /// const MAX: u8 = 100;
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(10..=MAX as ", stringify!($int_type), ", MAX as ", stringify!($int_type), ");")]
+        /// # assert_eq!(0x10, NZ.get());
+        /// # assert_eq!(100, nz.get());
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0..=127, 0);")]
+        /// ```
+        ///
+        /// #### Value out of range fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(1..=100, 101);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($range_expr:expr, $int_expr:expr) => {
+                const {
+                    let range: core::ops::RangeInclusive<$int_type> = $range_expr;
+                    let value = $crate::$int_type!(
+                        $int_expr,
+                        concat!(
+                            "nz::", stringify!($macro_name), "!: expected non-zero ",
+                            stringify!($int_type), " value"
+                        )
+                    );
+                    if value.get() < *range.start() || value.get() > *range.end() {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: expected value within `",
+                            stringify!($range_expr), "`"
+                        ));
+                    }
+                    value
+                }
+            };
+        }
+    };
+}
+
+/// Generates a range restricted non-zero macro from each macro name and
+/// integer type pair.
+macro_rules! gen_nz_in_range_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_in_range_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_in_range_macros!(
+    i8_in: i8,
+    i16_in: i16,
+    i32_in: i32,
+    i64_in: i64,
+    i128_in: i128,
+    isize_in: isize,
+    u8_in: u8,
+    u16_in: u16,
+    u32_in: u32,
+    u64_in: u64,
+    u128_in: u128,
+    usize_in: usize
+);
+
+/// Generates a non-zero slice maximum macro for the specified integer type.
+macro_rules! gen_nz_max_of_slice_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Computes the maximum of a constant slice of [`prim@", stringify!($int_type), "`] as a")]
+        #[doc = concat!("[`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// The elements of the slice are compared in a constant loop, so the
+        /// slice does not have to be unrolled by hand and only the maximum is
+        /// required to be non-zero. If the slice is empty or the maximum is
+        /// zero, a [`panic`] will occur at compile time with a distinct message
+        /// for each case.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant slice
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const TABLE: &[", stringify!($int_type), "] = &[3, 7, 0, 2];")]
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(TABLE);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(&[1, 2]);")]
+        /// # assert_eq!(7, NZ.get());
+        /// # assert_eq!(2, nz.get());
+        /// ```
+        ///
+        /// #### Empty slice fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(&[]);")]
+        /// ```
+        ///
+        /// #### Zero maximum fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(&[0, 0]);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($slice_expr:expr) => {
+                const {
+                    let slice: &[$int_type] = $slice_expr;
+                    if slice.is_empty() {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: expected non-empty slice"
+                        ));
+                    }
+                    let mut max = slice[0];
+                    let mut index = 1;
+                    while index < slice.len() {
+                        if slice[index] > max {
+                            max = slice[index];
+                        }
+                        index += 1;
+                    }
+                    match core::num::NonZero::<$int_type>::new(max) {
+                        Some(non_zero) => non_zero,
+                        None => panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: expected non-zero maximum"
+                        )),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero slice maximum macro from each macro name and integer
+/// type pair.
+macro_rules! gen_nz_max_of_slice_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_max_of_slice_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_max_of_slice_macros!(
+    max_of_slice_i8: i8,
+    max_of_slice_i16: i16,
+    max_of_slice_i32: i32,
+    max_of_slice_i64: i64,
+    max_of_slice_i128: i128,
+    max_of_slice_isize: isize,
+    max_of_slice_u8: u8,
+    max_of_slice_u16: u16,
+    max_of_slice_u32: u32,
+    max_of_slice_u64: u64,
+    max_of_slice_u128: u128,
+    max_of_slice_usize: usize
+);
+
+/// Generates a non-zero select macro for the specified integer type.
+macro_rules! gen_nz_select_macro {
+    ($macro_name:ident, $int_type:ident, $bool_macro:ident) => {
+        #[doc = concat!("Selects one of two literals, constant values or expressions that evaluate to non-zero")]
+        #[doc = concat!("[`prim@", stringify!($int_type), "`] based on a constant [`prim@bool`] condition resulting in a")]
+        #[doc = concat!("[`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// The first argument is the condition, the second one is the value
+        /// that is selected if the condition is `true` and the third one is the
+        /// value that is selected otherwise. Both values are checked regardless
+        /// of the condition, so if any of them evaluates to zero, a [`panic`]
+        /// will occur at compile time.
+        ///
+        #[doc = concat!("The expansion is the three argument form of [`nz::", stringify!($bool_macro), "!`][`crate::", stringify!($bool_macro), "`].")]
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literals
+        /// ```rust
+        /// # use std::num::NonZero;
+        /// const VERBOSE: bool = true;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(VERBOSE, 64, 8);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(!VERBOSE, 64, 8);")]
+        /// # assert_eq!(64, NZ.get());
+        /// # assert_eq!(8, nz.get());
+        /// ```
+        ///
+        /// #### Zero value of the branch not taken fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(true, 64, 0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($cond_expr:expr, $then_expr:expr, $else_expr:expr) => {
+                $crate::$bool_macro!($cond_expr, $then_expr, $else_expr)
+            };
+        }
+    };
+}
+
+/// Generates a non-zero select macro from each macro name, integer type and
+/// boolean conversion macro name triple.
+macro_rules! gen_nz_select_macros {
+    ($($macro_name:ident: $int_type:ident => $bool_macro:ident), *) => {
+        $(gen_nz_select_macro!($macro_name, $int_type, $bool_macro);)*
+    };
+}
+
+gen_nz_select_macros!(
+    select_i8: i8 => bool_i8,
+    select_i16: i16 => bool_i16,
+    select_i32: i32 => bool_i32,
+    select_i64: i64 => bool_i64,
+    select_i128: i128 => bool_i128,
+    select_isize: isize => bool_isize,
+    select_u8: u8 => bool_u8,
+    select_u16: u16 => bool_u16,
+    select_u32: u32 => bool_u32,
+    select_u64: u64 => bool_u64,
+    select_u128: u128 => bool_u128,
+    select_usize: usize => bool_usize
+);
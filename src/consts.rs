@@ -0,0 +1,83 @@
+//! Macros that declare [`NonZero`][`core::num::NonZero`] constant items.
+
+/// Generates a non-zero constant items macro for the specified integer type.
+macro_rules! gen_nz_consts_macro {
+    ($d:tt $macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Declares [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] constant items from literals, constant values or")]
+        #[doc = concat!("expressions that evaluate to [`prim@", stringify!($int_type), "`].")]
+        #[doc = r""]
+        /// Each entry is a name and a value separated by `=` and terminated by
+        /// `;`, which can be preceded by attributes and a visibility (e.g.
+        /// `pub(crate) PORT = 8080;`). Each value is checked individually and
+        /// if any of them evaluates to zero, a [`panic`] will occur at compile
+        /// time with a message that contains the name of the constant.
+        ///
+        /// # Examples
+        ///
+        /// #### Constant items
+        /// ```rust
+        /// mod config {
+        #[doc = concat!("     nz::", stringify!($macro_name), "! {")]
+        ///         /// Port of the server.
+        ///         pub PORT = 80;
+        ///         pub(crate) TIMEOUT = 30;
+        ///         RETRIES = 3;
+        ///     }
+        ///
+        /// #   pub fn retries() -> u32 { RETRIES.get() as u32 }
+        /// }
+        ///
+        /// # assert_eq!(80, config::PORT.get());
+        /// # assert_eq!(30, config::TIMEOUT.get());
+        /// # assert_eq!(3, config::retries());
+        /// ```
+        ///
+        /// #### Zero value fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" nz::", stringify!($macro_name), "! {")]
+        ///     PORT = 80;
+        ///     TIMEOUT = 0;
+        /// }
+        /// # let _ = (PORT, TIMEOUT);
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($d($d(#[$d attr:meta])* $d vis:vis $d const_name:ident = $d int_expr:expr;)*) => {
+                $d(
+                    $d(#[$d attr])*
+                    $d vis const $d const_name: core::num::NonZero<$int_type> = $crate::$int_type!(
+                        $d int_expr,
+                        concat!(
+                            "nz::", stringify!($macro_name), "!: expected non-zero ",
+                            stringify!($int_type), " value of `", stringify!($d const_name), "`"
+                        )
+                    );
+                )*
+            };
+        }
+    };
+}
+
+/// Generates a non-zero constant items macro from each macro name and integer
+/// type pair.
+macro_rules! gen_nz_consts_macros {
+    ($d:tt $($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_consts_macro!($d $macro_name, $int_type);)*
+    };
+}
+
+gen_nz_consts_macros!(
+    $
+    consts_i8: i8,
+    consts_i16: i16,
+    consts_i32: i32,
+    consts_i64: i64,
+    consts_i128: i128,
+    consts_isize: isize,
+    consts_u8: u8,
+    consts_u16: u16,
+    consts_u32: u32,
+    consts_u64: u64,
+    consts_u128: u128,
+    consts_usize: usize
+);
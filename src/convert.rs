@@ -0,0 +1,1073 @@
+//! Macros that convert values to [`NonZero`][`core::num::NonZero`] at compile
+//! time.
+
+/// Converts a constant [`NonZero`][`core::num::NonZero`] to a
+/// [`NonZero`][`core::num::NonZero`] of the specified wider integer type at
+/// compile time.
+///
+/// Only lossless conversions are accepted, that is, the ones for which the
+/// target type implements [`From`] the source type (e.g. `NonZero<u8>` to
+/// `NonZero<u32>` or `NonZero<u16>` to `NonZero<i32>`). A widened non-zero
+/// value is always non-zero, so the conversion never panics. Narrowing and
+/// other lossy conversions fail to compile, for which
+/// [`nz::cast_truncating!`][`crate::cast_truncating`] can be used instead.
+///
+/// # Examples
+///
+/// #### From constant value
+/// ```rust
+/// # use std::num::NonZero;
+/// const NZ_U8: NonZero<u8> = nz::u8!(200);
+/// const NZ_U32: NonZero<u32> = nz::cast!(u32, NZ_U8);
+/// let nz_i16 = nz::cast!(i16, NZ_U8);
+/// # assert_eq!(200, NZ_U32.get());
+/// # assert_eq!(200, nz_i16.get());
+/// ```
+///
+/// #### From constant expression
+/// ```rust
+/// let nz = nz::cast!(u64, nz::u16!(1 << 15));
+/// # assert_eq!(1 << 15, nz.get());
+/// ```
+///
+/// #### Narrowing conversion fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::cast!(u8, nz::u32!(1));
+/// ```
+///
+/// #### Signed to unsigned conversion fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::cast!(u64, nz::i8!(1));
+/// ```
+#[macro_export]
+macro_rules! cast {
+    ($int_type:ident, $nz_expr:expr) => {
+        const {
+            let non_zero = $nz_expr;
+            $crate::__private::assert_lossless::<
+                _,
+                core::num::NonZero<$int_type>,
+            >(&non_zero);
+            match core::num::NonZero::<$int_type>::new(
+                non_zero.get() as $int_type
+            ) {
+                Some(non_zero) => non_zero,
+                // the conversion is lossless, so the value is still non-zero
+                None => unreachable!(),
+            }
+        }
+    };
+}
+
+/// Converts a constant [`NonZero`][`core::num::NonZero`] to a
+/// [`NonZero`][`core::num::NonZero`] of the specified integer type with an
+/// `as` cast at compile time.
+///
+/// Unlike [`nz::cast!`][`crate::cast`], any conversion is accepted, including
+/// the ones that truncate or wrap the value. Since truncation can result in
+/// zero (e.g. `256u32` truncates to `0u8`), the converted value is checked
+/// again and a [`panic`] will occur at compile time if it is zero.
+///
+/// # Examples
+///
+/// #### From constant value
+/// ```rust
+/// # use std::num::NonZero;
+/// const NZ_U32: NonZero<u32> = nz::u32!(0x1_2345);
+/// const NZ_U8: NonZero<u8> = nz::cast_truncating!(u8, NZ_U32);
+/// let nz_i8 = nz::cast_truncating!(i8, nz::u16!(0xff));
+/// # assert_eq!(0x45, NZ_U8.get());
+/// # assert_eq!(-1, nz_i8.get());
+/// ```
+///
+/// #### Truncation to zero fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::cast_truncating!(u8, nz::u32!(256));
+/// ```
+#[macro_export]
+macro_rules! cast_truncating {
+    ($int_type:ident, $nz_expr:expr) => {
+        const {
+            let non_zero = $nz_expr;
+            match core::num::NonZero::<$int_type>::new(
+                non_zero.get() as $int_type
+            ) {
+                Some(non_zero) => non_zero,
+                None => panic!(concat!(
+                    "nz::cast_truncating!: truncation to ",
+                    stringify!($int_type),
+                    " resulted in zero"
+                )),
+            }
+        }
+    };
+}
+
+/// Converts a constant [`char`] to a [`NonZero`][`core::num::NonZero`] of the
+/// specified integer type at compile time.
+///
+/// The result is the Unicode scalar value of the character, which is non-zero
+/// for every character except `'\0'`. Any integer type can be used, as long
+/// as the scalar value fits into it (e.g. [`prim@u8`] for ASCII characters or
+/// [`prim@u32`] for any character). If the character is `'\0'` or its scalar
+/// value does not fit into the integer type, a [`panic`] will occur at
+/// compile time.
+///
+/// # Examples
+///
+/// #### From character literal
+/// ```rust
+/// # use std::num::NonZero;
+/// const NZ: NonZero<u32> = nz::from_char!(u32, 'A');
+/// let nz = nz::from_char!(u8, 'z');
+/// let nz_max = nz::from_char!(u32, char::MAX);
+/// # assert_eq!(65, NZ.get());
+/// # assert_eq!(b'z', nz.get());
+/// # assert_eq!(0x10_ffff, nz_max.get());
+/// ```
+///
+/// #### Null character fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::from_char!(u32, '\0');
+/// ```
+///
+/// #### Character that does not fit fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::from_char!(u8, '€');
+/// ```
+#[macro_export]
+macro_rules! from_char {
+    ($int_type:ident, $char_expr:expr) => {
+        const {
+            let char_value: char = $char_expr;
+            let scalar = char_value as u32;
+            if scalar as u128 > <$int_type>::MAX as u128 {
+                panic!(concat!(
+                    "nz::from_char!: expected character that fits in ",
+                    stringify!($int_type)
+                ));
+            }
+            match core::num::NonZero::<$int_type>::new(scalar as $int_type) {
+                Some(non_zero) => non_zero,
+                None => panic!("nz::from_char!: expected non-null character"),
+            }
+        }
+    };
+}
+
+/// Converts a constant [`NonZero`][`core::num::NonZero`] to a
+/// [`NonZero`][`core::num::NonZero`] of the specified integer type at compile
+/// time, if the value fits into it.
+///
+/// This is the constant counterpart of the [`TryFrom`] implementations between
+/// [`NonZero`][`core::num::NonZero`] types. Unlike
+/// [`nz::cast!`][`crate::cast`], narrowing and sign changing conversions are
+/// accepted as long as the value is representable by the target type. If the
+/// value does not fit into the target type, a [`panic`] will occur at compile
+/// time. For runtime values, [`nz::try_from_nonzero!`][`crate::try_from_nonzero`]
+/// can be used instead.
+///
+/// # Examples
+///
+/// #### From constant value
+/// ```rust
+/// # use std::num::NonZero;
+/// const NZ_U32: NonZero<u32> = nz::u32!(200);
+/// const NZ_U8: NonZero<u8> = nz::from_nonzero!(u8, NZ_U32);
+/// let nz_i16 = nz::from_nonzero!(i16, nz::i64!(-300));
+/// # assert_eq!(200, NZ_U8.get());
+/// # assert_eq!(-300, nz_i16.get());
+/// ```
+///
+/// #### Value that does not fit fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::from_nonzero!(u8, nz::u32!(256));
+/// ```
+///
+/// #### Negative value to unsigned type fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::from_nonzero!(u64, nz::i8!(-1));
+/// ```
+#[macro_export]
+macro_rules! from_nonzero {
+    ($int_type:ident, $nz_expr:expr) => {
+        const {
+            let value = $nz_expr.get();
+            let converted = value as $int_type;
+            // A non-zero value is negative if it is less than one, which
+            // avoids comparing unsigned values against zero.
+            if $crate::__private::same_type(converted as _, value) != value
+                || (converted < 1) != (value < 1)
+            {
+                panic!(concat!(
+                    "nz::from_nonzero!: value of `",
+                    stringify!($nz_expr),
+                    "` does not fit into ",
+                    stringify!($int_type)
+                ));
+            }
+            match core::num::NonZero::<$int_type>::new(converted) {
+                Some(non_zero) => non_zero,
+                // the value fits into the type, so it is still non-zero
+                None => unreachable!(),
+            }
+        }
+    };
+}
+
+/// Converts a [`NonZero`][`core::num::NonZero`] to a
+/// [`NonZero`][`core::num::NonZero`] of the specified integer type, returning a
+/// [`Result`].
+///
+/// The macro expands to the [`TryFrom`] conversion between the
+/// [`NonZero`][`core::num::NonZero`] types, so runtime values can also be
+/// converted. An [`Err`] is returned if the value does not fit into the target
+/// type.
+///
+/// # Examples
+///
+/// #### From runtime value
+/// ```rust
+/// # use std::num::NonZero;
+/// let nz_u32 = NonZero::new(200u32).unwrap();
+/// let nz: Result<NonZero<u8>, _> = nz::try_from_nonzero!(u8, nz_u32);
+/// let err = nz::try_from_nonzero!(u8, nz::u32!(256));
+/// # assert_eq!(Ok(200), nz.map(NonZero::get));
+/// # assert!(err.is_err());
+/// ```
+#[macro_export]
+macro_rules! try_from_nonzero {
+    ($int_type:ident, $nz_expr:expr) => {
+        <core::num::NonZero<$int_type> as core::convert::TryFrom<_>>::try_from(
+            $nz_expr,
+        )
+    };
+}
+
+/// Generates a non-zero boolean conversion macro for the specified integer
+/// type.
+macro_rules! gen_nz_bool_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Converts a constant [`bool`] to a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// With a single argument, `true` is converted to `1` and `false` fails
+        /// to compile, because it would be converted to zero. With three
+        /// arguments, the second or the third argument is selected for `true`
+        /// and `false` respectively. Both of them must be literals, constant
+        /// values or expressions that evaluate to non-zero
+        #[doc = concat!("[`prim@", stringify!($int_type), "`], otherwise a [`panic`] will occur at compile time.")]
+        ///
+        /// # Examples
+        ///
+        /// #### From constant value
+        /// ```rust
+        /// # use std::num::NonZero;
+        /// const ENABLED: bool = true;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(ENABLED);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(!ENABLED, 2, 4);")]
+        /// # assert_eq!(1, NZ.get());
+        /// # assert_eq!(4, nz.get());
+        /// ```
+        ///
+        /// #### False fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(1 > 2);")]
+        /// ```
+        ///
+        /// #### Zero value fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(true, 1, 0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($bool_expr:expr) => {
+                $crate::$int_type!(
+                    {
+                        let bool_value: bool = $bool_expr;
+                        bool_value as $int_type
+                    },
+                    concat!("nz::", stringify!($macro_name), "!: expected `true`")
+                )
+            };
+            ($bool_expr:expr, $true_expr:expr, $false_expr:expr) => {
+                const {
+                    let bool_value: bool = $bool_expr;
+                    let true_value = $crate::__nz_operand!($macro_name, $int_type, $true_expr);
+                    let false_value = $crate::__nz_operand!($macro_name, $int_type, $false_expr);
+                    if bool_value {
+                        true_value
+                    } else {
+                        false_value
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero boolean conversion macro from each macro name and
+/// integer type pair.
+macro_rules! gen_nz_bool_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_bool_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_bool_macros!(
+    bool_i8: i8,
+    bool_i16: i16,
+    bool_i32: i32,
+    bool_i64: i64,
+    bool_i128: i128,
+    bool_isize: isize,
+    bool_u8: u8,
+    bool_u16: u16,
+    bool_u32: u32,
+    bool_u64: u64,
+    bool_u128: u128,
+    bool_usize: usize
+);
+
+/// Generates a non-zero pointer-sized integer macro that checks the value
+/// against the specified pointer width.
+macro_rules! gen_nz_ptr_width_macro {
+    ($macro_name:ident, $int_type:ident, $signed:literal, $example:literal) => {
+        #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] from a literal, a constant value")]
+        #[doc = concat!("or expression that evaluates to [`prim@", stringify!($int_type), "`] and fits into ", stringify!($int_type), " of the")]
+        /// specified pointer width.
+        ///
+        /// The first argument is the pointer width in bits, which must be `16`,
+        /// `32` or `64`. Checking the value against the narrowest pointer width
+        /// of the supported targets makes sure that the constant is valid on
+        /// all of them, not just on the target it is compiled for. If the
+        /// pointer width is not supported, the value does not fit into it or
+        /// evaluates to zero, a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literal
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(16, ", $example, ");")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(32, 1 << 20);")]
+        #[doc = concat!(" # assert_eq!(", $example, ", NZ.get());")]
+        /// # assert_eq!(1 << 20, nz.get());
+        /// ```
+        ///
+        /// #### Value that does not fit fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(16, 1 << 20);")]
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(64, 0);")]
+        /// ```
+        ///
+        /// #### Unsupported pointer width fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(8, 1);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($width_expr:expr, $int_expr:expr) => {
+                const {
+                    let width: u32 = $width_expr;
+                    if width != 16 && width != 32 && width != 64 {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: expected pointer width of 16, 32 or 64"
+                        ));
+                    }
+                    let non_zero = $crate::__nz_operand!($macro_name, $int_type, $int_expr);
+                    let value = non_zero.get() as i128;
+                    let (min, max) = if $signed {
+                        (-(1 << (width - 1)), (1 << (width - 1)) - 1)
+                    } else {
+                        (0, (1 << width) - 1)
+                    };
+                    if value < min || value > max {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: expected value that fits in ",
+                            stringify!($width_expr), "-bit ", stringify!($int_type)
+                        ));
+                    }
+                    non_zero
+                }
+            };
+        }
+    };
+}
+
+gen_nz_ptr_width_macro!(isize_for, isize, true, "-0x8000");
+gen_nz_ptr_width_macro!(usize_for, usize, false, "0xffff");
+
+/// Generates a macro that creates a non-zero value with its primitive value for
+/// the specified integer type.
+macro_rules! gen_nz_with_raw_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates a tuple of a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] and its [`prim@", stringify!($int_type), "`] value from a literal,")]
+        #[doc = concat!("a constant value or expression that evaluates to [`prim@", stringify!($int_type), "`].")]
+        #[doc = r""]
+        /// The argument is evaluated only once and the second element of the
+        /// tuple is the validated primitive value, so the two representations
+        /// cannot diverge. If the argument evaluates to zero, a [`panic`] will
+        /// occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant value
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const CAPACITY: (NonZero<", stringify!($int_type), ">, ", stringify!($int_type), ") = nz::", stringify!($macro_name), "!(0x10);")]
+        #[doc = concat!(" let (nz, raw) = nz::", stringify!($macro_name), "!(CAPACITY.1 * 2);")]
+        /// # assert_eq!(0x10, CAPACITY.0.get());
+        /// # assert_eq!(0x10, CAPACITY.1);
+        /// # assert_eq!(raw, nz.get());
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {
+                const {
+                    let non_zero = $crate::$int_type!(
+                        $int_expr,
+                        concat!(
+                            "nz::", stringify!($macro_name), "!: expected non-zero ",
+                            stringify!($int_type), " value"
+                        )
+                    );
+                    (non_zero, non_zero.get())
+                }
+            };
+        }
+    };
+}
+
+/// Generates a macro that creates a non-zero value with its primitive value
+/// from each macro name and integer type pair.
+macro_rules! gen_nz_with_raw_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_with_raw_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_with_raw_macros!(
+    with_raw_i8: i8,
+    with_raw_i16: i16,
+    with_raw_i32: i32,
+    with_raw_i64: i64,
+    with_raw_i128: i128,
+    with_raw_isize: isize,
+    with_raw_u8: u8,
+    with_raw_u16: u16,
+    with_raw_u32: u32,
+    with_raw_u64: u64,
+    with_raw_u128: u128,
+    with_raw_usize: usize
+);
+
+/// Generates a macro that creates a non-zero value from its byte
+/// representation for the specified integer type and byte order.
+macro_rules! gen_nz_from_bytes_macro {
+    ($macro_name:ident, $int_type:ident, $order_name:literal, $from_bytes_fn:ident, $to_bytes_fn:ident) => {
+        #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] from a constant byte array in ", $order_name, " endian")]
+        #[doc = concat!("byte order using [`", stringify!($int_type), "::", stringify!($from_bytes_fn), "`].")]
+        #[doc = r""]
+        #[doc = concat!("The argument must be a constant `[u8; N]` where `N` is the size of [`prim@", stringify!($int_type), "`].")]
+        /// If all of the bytes are zero, a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant byte array
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const BYTES: [u8; core::mem::size_of::<", stringify!($int_type), ">()] = (0x10 as ", stringify!($int_type), ").", stringify!($to_bytes_fn), "();")]
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(BYTES);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(NZ.get().", stringify!($to_bytes_fn), "());")]
+        /// # assert_eq!(0x10, NZ.get());
+        /// # assert_eq!(NZ, nz);
+        /// ```
+        ///
+        /// #### Zero bytes fail to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!([0; core::mem::size_of::<", stringify!($int_type), ">()]);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($bytes_expr:expr) => {
+                const {
+                    let bytes: [u8; core::mem::size_of::<$int_type>()] = $bytes_expr;
+                    match core::num::NonZero::<$int_type>::new(<$int_type>::$from_bytes_fn(bytes)) {
+                        Some(non_zero) => non_zero,
+                        None => panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: expected bytes of non-zero ",
+                            stringify!($int_type), " value"
+                        )),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates macros that create non-zero values from big and little endian
+/// bytes from each macro name pair and integer type.
+macro_rules! gen_nz_from_bytes_macros {
+    ($($be_macro_name:ident, $le_macro_name:ident: $int_type:ident), *) => {
+        $(
+            gen_nz_from_bytes_macro!($be_macro_name, $int_type, "big", from_be_bytes, to_be_bytes);
+            gen_nz_from_bytes_macro!($le_macro_name, $int_type, "little", from_le_bytes, to_le_bytes);
+        )*
+    };
+}
+
+gen_nz_from_bytes_macros!(
+    from_be_bytes_i8, from_le_bytes_i8: i8,
+    from_be_bytes_i16, from_le_bytes_i16: i16,
+    from_be_bytes_i32, from_le_bytes_i32: i32,
+    from_be_bytes_i64, from_le_bytes_i64: i64,
+    from_be_bytes_i128, from_le_bytes_i128: i128,
+    from_be_bytes_isize, from_le_bytes_isize: isize,
+    from_be_bytes_u8, from_le_bytes_u8: u8,
+    from_be_bytes_u16, from_le_bytes_u16: u16,
+    from_be_bytes_u32, from_le_bytes_u32: u32,
+    from_be_bytes_u64, from_le_bytes_u64: u64,
+    from_be_bytes_u128, from_le_bytes_u128: u128,
+    from_be_bytes_usize, from_le_bytes_usize: usize
+);
+
+/// Generates a macro that creates the byte representation of a non-zero value
+/// for the specified integer type and byte order.
+macro_rules! gen_nz_to_bytes_macro {
+    ($macro_name:ident, $int_type:ident, $order_name:literal, $to_bytes_fn:ident) => {
+        #[doc = concat!("Creates the ", $order_name, " endian byte array of a literal, constant value or expression that evaluates to")]
+        #[doc = concat!("non-zero [`prim@", stringify!($int_type), "`] using [`", stringify!($int_type), "::", stringify!($to_bytes_fn), "`] at compile time.")]
+        #[doc = r""]
+        #[doc = concat!("The result is a `[u8; N]` where `N` is the size of [`prim@", stringify!($int_type), "`], so non-zero constants")]
+        /// can be embedded as bytes without a serializer. If the argument
+        /// evaluates to zero, a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literal
+        /// ```rust
+        #[doc = concat!(" const BYTES: [u8; core::mem::size_of::<", stringify!($int_type), ">()] = nz::", stringify!($macro_name), "!(0x10);")]
+        #[doc = concat!(" # assert_eq!((0x10 as ", stringify!($int_type), ").", stringify!($to_bytes_fn), "(), BYTES);")]
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {
+                const { $crate::__nz_operand!($macro_name, $int_type, $int_expr).get().$to_bytes_fn() }
+            };
+        }
+    };
+}
+
+/// Generates macros that create big and little endian bytes of non-zero values
+/// from each macro name pair and integer type.
+macro_rules! gen_nz_to_bytes_macros {
+    ($($be_macro_name:ident, $le_macro_name:ident: $int_type:ident), *) => {
+        $(
+            gen_nz_to_bytes_macro!($be_macro_name, $int_type, "big", to_be_bytes);
+            gen_nz_to_bytes_macro!($le_macro_name, $int_type, "little", to_le_bytes);
+        )*
+    };
+}
+
+gen_nz_to_bytes_macros!(
+    to_be_bytes_i8, to_le_bytes_i8: i8,
+    to_be_bytes_i16, to_le_bytes_i16: i16,
+    to_be_bytes_i32, to_le_bytes_i32: i32,
+    to_be_bytes_i64, to_le_bytes_i64: i64,
+    to_be_bytes_i128, to_le_bytes_i128: i128,
+    to_be_bytes_isize, to_le_bytes_isize: isize,
+    to_be_bytes_u8, to_le_bytes_u8: u8,
+    to_be_bytes_u16, to_le_bytes_u16: u16,
+    to_be_bytes_u32, to_le_bytes_u32: u32,
+    to_be_bytes_u64, to_le_bytes_u64: u64,
+    to_be_bytes_u128, to_le_bytes_u128: u128,
+    to_be_bytes_usize, to_le_bytes_usize: usize
+);
+
+/// Generates a non-zero mapping macro for the specified integer type.
+macro_rules! gen_nz_map_macro {
+    ($d:tt $macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Maps a literal, constant value or expression that evaluates to non-zero [`prim@", stringify!($int_type), "`]")]
+        #[doc = concat!("to a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] with a constant transformation at compile time.")]
+        #[doc = r""]
+        /// The transformation is either closure-like syntax (e.g.
+        /// `|x| x.wrapping_mul(3)`), whose body is evaluated with the value bound
+        /// to the parameter, or a path to a `const fn` that takes and returns
+        #[doc = concat!("[`prim@", stringify!($int_type), "`]. The body or the function must be constant evaluable, otherwise it")]
+        /// fails to compile. If the value or the result evaluates to zero, a
+        /// [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### With closure-like syntax
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(5, |x| x.wrapping_mul(3));")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(NZ.get(), |x| x / 5 + 1);")]
+        /// # assert_eq!(15, NZ.get());
+        /// # assert_eq!(4, nz.get());
+        /// ```
+        ///
+        /// #### With constant function
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const fn double(x: ", stringify!($int_type), ") -> ", stringify!($int_type), " {")]
+        ///     x * 2
+        /// }
+        ///
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(8, double);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(1, ", stringify!($int_type), "::reverse_bits);")]
+        /// # assert_eq!(16, NZ.get());
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::reverse_bits(1), nz.get());")]
+        /// ```
+        ///
+        /// #### Zero result fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(3, |x| x - 3);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($d int_expr:expr, |$d value:ident| $d body_expr:expr) => {
+                const {
+                    let $d value: $int_type =
+                        $crate::__nz_operand!($macro_name, $int_type, $d int_expr).get();
+                    let mapped: $int_type = $d body_expr;
+                    match core::num::NonZero::<$int_type>::new(mapped) {
+                        Some(non_zero) => non_zero,
+                        None => panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: expected non-zero ",
+                            stringify!($int_type), " result"
+                        )),
+                    }
+                }
+            };
+            ($d int_expr:expr, $d fn_expr:expr) => {
+                $crate::$macro_name!($d int_expr, |value| ($d fn_expr)(value))
+            };
+        }
+    };
+}
+
+/// Generates a non-zero mapping macro from each macro name and integer type
+/// pair.
+macro_rules! gen_nz_map_macros {
+    ($d:tt $($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_map_macro!($d $macro_name, $int_type);)*
+    };
+}
+
+gen_nz_map_macros!(
+    $
+    map_i8: i8,
+    map_i16: i16,
+    map_i32: i32,
+    map_i64: i64,
+    map_i128: i128,
+    map_isize: isize,
+    map_u8: u8,
+    map_u16: u16,
+    map_u32: u32,
+    map_u64: u64,
+    map_u128: u128,
+    map_usize: usize
+);
+
+/// Generates a non-zero enum discriminant macro for the specified integer type.
+macro_rules! gen_nz_from_discriminant_macro {
+    ($macro_name:ident, $int_type:ident, $repr_type:ident) => {
+        #[doc = concat!("Converts a constant variant of a fieldless enum to a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`]")]
+        /// of its discriminant at compile time.
+        ///
+        #[doc = concat!("The variant is converted with an `as` cast, so the enum should have a `#[repr]` attribute (e.g. `#[repr(", stringify!($repr_type), ")]`)")]
+        /// to guarantee that every discriminant fits into the integer type
+        /// without truncation. The 128-bit integer types cannot be used in
+        /// `#[repr]` on the `MSRV` of the crate, therefore narrower types of
+        /// the same signedness are used in their examples. If the discriminant
+        /// is zero, a [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From enum variant
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" #[repr(", stringify!($repr_type), ")]")]
+        /// #[derive(Clone, Copy)]
+        /// enum Message {
+        ///     Ping = 1,
+        ///     Pong,
+        /// }
+        ///
+        #[doc = concat!(" const PING: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(Message::Ping);")]
+        #[doc = concat!(" let pong = nz::", stringify!($macro_name), "!(Message::Pong);")]
+        /// # assert_eq!(1, PING.get());
+        /// # assert_eq!(2, pong.get());
+        /// ```
+        ///
+        /// #### Zero discriminant fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" #[repr(", stringify!($repr_type), ")]")]
+        /// enum Message {
+        ///     Empty,
+        ///     Ping,
+        /// }
+        ///
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(Message::Empty);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($variant_expr:expr) => {
+                $crate::$int_type!(
+                    $variant_expr as $int_type,
+                    concat!(
+                        "nz::", stringify!($macro_name), "!: expected non-zero discriminant of `",
+                        stringify!($variant_expr), "`"
+                    )
+                )
+            };
+        }
+    };
+}
+
+/// Generates a non-zero enum discriminant macro from each macro name, integer
+/// type and example representation type triple.
+macro_rules! gen_nz_from_discriminant_macros {
+    ($($macro_name:ident: $int_type:ident => $repr_type:ident), *) => {
+        $(gen_nz_from_discriminant_macro!($macro_name, $int_type, $repr_type);)*
+    };
+}
+
+gen_nz_from_discriminant_macros!(
+    from_discriminant_i8: i8 => i8,
+    from_discriminant_i16: i16 => i16,
+    from_discriminant_i32: i32 => i32,
+    from_discriminant_i64: i64 => i64,
+    from_discriminant_i128: i128 => i64,
+    from_discriminant_isize: isize => isize,
+    from_discriminant_u8: u8 => u8,
+    from_discriminant_u16: u16 => u16,
+    from_discriminant_u32: u32 => u32,
+    from_discriminant_u64: u64 => u64,
+    from_discriminant_u128: u128 => u64,
+    from_discriminant_usize: usize => usize
+);
+
+/// Generates a non-zero decimal formatting macro for the specified integer
+/// type.
+macro_rules! gen_nz_to_str_macro {
+    ($macro_name:ident, $int_type:ident, $format_fn:ident, $wide_type:ident) => {
+        #[doc = concat!("Formats a literal, constant value or expression that evaluates to non-zero [`prim@", stringify!($int_type), "`]")]
+        /// into a decimal `&'static str` at compile time.
+        ///
+        /// The number is formatted by a constant formatter of the crate, so no
+        /// procedural macro is needed for creating labels from constants. The
+        /// argument is evaluated in a constant item, therefore it cannot refer
+        /// to generic parameters. If the argument evaluates to zero, a [`panic`]
+        /// will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant value
+        /// ```rust
+        #[doc = concat!(" const ID: ", stringify!($int_type), " = 42;")]
+        #[doc = concat!(" const LABEL: &str = nz::", stringify!($macro_name), "!(ID);")]
+        #[doc = concat!(" let max = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX);")]
+        /// # assert_eq!("42", LABEL);
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::MAX.to_string(), max);")]
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {{
+                const FORMATTED: (
+                    [u8; $crate::__private::FORMAT_LEN],
+                    usize,
+                ) = $crate::__private::$format_fn(
+                    $crate::__nz_operand!($macro_name, $int_type, $int_expr).get() as $wide_type,
+                );
+                // referencing a constant item makes the bytes `'static`
+                const BYTES: &[u8; $crate::__private::FORMAT_LEN] = &FORMATTED.0;
+                const {
+                    match core::str::from_utf8(BYTES.split_at(FORMATTED.1).1) {
+                        Ok(formatted) => formatted,
+                        // the formatted number only contains ASCII characters
+                        Err(_) => unreachable!(),
+                    }
+                }
+            }};
+        }
+    };
+}
+
+/// Generates a non-zero decimal formatting macro from each macro name, integer
+/// type and formatting function triple.
+macro_rules! gen_nz_to_str_macros {
+    ($($macro_name:ident: $int_type:ident => $format_fn:ident($wide_type:ident)), *) => {
+        $(gen_nz_to_str_macro!($macro_name, $int_type, $format_fn, $wide_type);)*
+    };
+}
+
+gen_nz_to_str_macros!(
+    to_str_i8: i8 => format_i128(i128),
+    to_str_i16: i16 => format_i128(i128),
+    to_str_i32: i32 => format_i128(i128),
+    to_str_i64: i64 => format_i128(i128),
+    to_str_i128: i128 => format_i128(i128),
+    to_str_isize: isize => format_i128(i128),
+    to_str_u8: u8 => format_u128(u128),
+    to_str_u16: u16 => format_u128(u128),
+    to_str_u32: u32 => format_u128(u128),
+    to_str_u64: u64 => format_u128(u128),
+    to_str_u128: u128 => format_u128(u128),
+    to_str_usize: usize => format_u128(u128)
+);
+
+/// Generates a non-zero macro that rejects zero decimal digits for the
+/// specified unsigned integer type.
+macro_rules! gen_nz_no_zero_digits_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] from a literal, a constant value or")]
+        #[doc = concat!("expression that evaluates to [`prim@", stringify!($int_type), "`] without any `0` decimal digit.")]
+        #[doc = r""]
+        /// The digits are checked by a constant function of the crate. If the
+        /// argument evaluates to zero or any of its decimal digits is `0`, a
+        /// [`panic`] will occur at compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literal
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(123);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(NZ.get() - 2);")]
+        /// # assert_eq!(123, NZ.get());
+        /// # assert_eq!(121, nz.get());
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        ///
+        /// #### Zero digit fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(105);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {
+                const {
+                    let value = $crate::__nz_operand!($macro_name, $int_type, $int_expr);
+                    if $crate::__private::has_zero_digit(value.get() as u128) {
+                        panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: expected no zero decimal digit in `",
+                            stringify!($int_expr), "`"
+                        ));
+                    }
+                    value
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero macro that rejects zero decimal digits from each macro
+/// name and unsigned integer type pair.
+macro_rules! gen_nz_no_zero_digits_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_no_zero_digits_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_no_zero_digits_macros!(
+    no_zero_digits_u8: u8,
+    no_zero_digits_u16: u16,
+    no_zero_digits_u32: u32,
+    no_zero_digits_u64: u64,
+    no_zero_digits_u128: u128,
+    no_zero_digits_usize: usize
+);
+
+/// Generates a fallible non-zero conversion macro for the specified integer
+/// type.
+macro_rules! gen_nz_try_into_macro {
+    ($macro_name:ident, $int_type:ident, $unfit_type:ident::$unfit_bound:ident) => {
+        #[doc = concat!("Converts a [`NonZero`][`core::num::NonZero`] to a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`], returning a [`Result`].")]
+        #[doc = r""]
+        #[doc = concat!("It is the same as [`nz::try_from_nonzero!`][`crate::try_from_nonzero`] with [`prim@", stringify!($int_type), "`] as the target")]
+        /// type, so runtime values can also be converted. An [`Err`] (e.g.
+        /// [`TryFromIntError`][`core::num::TryFromIntError`] for narrowing
+        /// conversions) is returned if the value does not fit into the target
+        /// type.
+        ///
+        /// # Examples
+        ///
+        /// #### From runtime value
+        /// ```rust
+        /// # use std::num::NonZero;
+        /// let nz_u8 = NonZero::new(100u8).unwrap();
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(nz_u8);")]
+        #[doc = concat!(" let err = nz::", stringify!($macro_name), "!(NonZero::<", stringify!($unfit_type), ">::", stringify!($unfit_bound), ");")]
+        /// # assert_eq!(Ok(100), nz.map(NonZero::get));
+        /// # assert!(err.is_err());
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($nz_expr:expr) => {
+                $crate::try_from_nonzero!($int_type, $nz_expr)
+            };
+        }
+    };
+}
+
+/// Generates a fallible non-zero conversion macro from each macro name,
+/// integer type and unfitting bound triple.
+macro_rules! gen_nz_try_into_macros {
+    ($($macro_name:ident: $int_type:ident => $unfit_type:ident::$unfit_bound:ident), *) => {
+        $(gen_nz_try_into_macro!($macro_name, $int_type, $unfit_type::$unfit_bound);)*
+    };
+}
+
+gen_nz_try_into_macros!(
+    try_into_i8: i8 => u128::MAX,
+    try_into_i16: i16 => u128::MAX,
+    try_into_i32: i32 => u128::MAX,
+    try_into_i64: i64 => u128::MAX,
+    try_into_i128: i128 => u128::MAX,
+    try_into_isize: isize => u128::MAX,
+    try_into_u8: u8 => i8::MIN,
+    try_into_u16: u16 => i8::MIN,
+    try_into_u32: u32 => i8::MIN,
+    try_into_u64: u64 => i8::MIN,
+    try_into_u128: u128 => i8::MIN,
+    try_into_usize: usize => i8::MIN
+);
+
+/// Generates a one-based non-zero index macro for the specified unsigned
+/// integer type.
+macro_rules! gen_nz_from_index_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates a one-based [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] from a literal, a constant value or expression")]
+        #[doc = concat!("that evaluates to a zero-based [`prim@", stringify!($int_type), "`] index at compile time.")]
+        #[doc = r""]
+        /// The result is `index + 1`, which is always non-zero, so zero remains
+        /// reserved (e.g. for "none" in slotmap-style identifiers). If the
+        #[doc = concat!("addition overflows, which happens for [`", stringify!($int_type), "::MAX`], a [`panic`] will occur at")]
+        /// compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### From integer literals
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const FIRST: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(0);")]
+        #[doc = concat!(" let last = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX - 1);")]
+        /// # assert_eq!(1, FIRST.get());
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::MAX, last.get());")]
+        /// ```
+        ///
+        /// #### Overflowing index fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(", stringify!($int_type), "::MAX);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($index_expr:expr) => {
+                const {
+                    let index: $int_type = $index_expr;
+                    match index.checked_add(1) {
+                        Some(id) => match core::num::NonZero::<$int_type>::new(id) {
+                            Some(non_zero) => non_zero,
+                            // the successor of an unsigned value is non-zero
+                            None => unreachable!(),
+                        },
+                        None => panic!(concat!(
+                            "nz::", stringify!($macro_name), "!: index `", stringify!($index_expr),
+                            "` overflowed"
+                        )),
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a one-based non-zero index macro from each macro name and
+/// unsigned integer type pair.
+macro_rules! gen_nz_from_index_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_from_index_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_from_index_macros!(
+    from_index_u8: u8,
+    from_index_u16: u16,
+    from_index_u32: u32,
+    from_index_u64: u64,
+    from_index_u128: u128,
+    from_index_usize: usize
+);
+
+/// Generates a zero-based index macro for the specified unsigned integer type.
+macro_rules! gen_nz_to_index_macro {
+    ($macro_name:ident, $from_index_name:ident, $int_type:ident) => {
+        #[doc = concat!("Converts a one-based [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] to a zero-based [`prim@", stringify!($int_type), "`] index.")]
+        #[doc = r""]
+        #[doc = concat!("It is the inverse of [`nz::", stringify!($from_index_name), "!`][`crate::", stringify!($from_index_name), "`]. The result is `value.get() - 1`,")]
+        /// which cannot underflow since the value is non-zero, so the macro can
+        /// be used with runtime values too.
+        ///
+        /// # Examples
+        ///
+        /// #### From non-zero values
+        /// ```rust
+        #[doc = concat!(" const FIRST: ", stringify!($int_type), " = nz::", stringify!($macro_name), "!(nz::", stringify!($int_type), "!(1));")]
+        #[doc = concat!(" let id = nz::", stringify!($from_index_name), "!(41);")]
+        #[doc = concat!(" let index = nz::", stringify!($macro_name), "!(id);")]
+        /// # assert_eq!(0, FIRST);
+        /// # assert_eq!(41, index);
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($nz_expr:expr) => {{
+                let non_zero: core::num::NonZero<$int_type> = $nz_expr;
+                non_zero.get() - 1
+            }};
+        }
+    };
+}
+
+/// Generates a zero-based index macro from each macro name pair and unsigned
+/// integer type.
+macro_rules! gen_nz_to_index_macros {
+    ($($macro_name:ident, $from_index_name:ident: $int_type:ident), *) => {
+        $(gen_nz_to_index_macro!($macro_name, $from_index_name, $int_type);)*
+    };
+}
+
+gen_nz_to_index_macros!(
+    to_index_u8, from_index_u8: u8,
+    to_index_u16, from_index_u16: u16,
+    to_index_u32, from_index_u32: u32,
+    to_index_u64, from_index_u64: u64,
+    to_index_u128, from_index_u128: u128,
+    to_index_usize, from_index_usize: usize
+);
@@ -0,0 +1,75 @@
+//! Macros that generate non-zero macros for custom integer types.
+
+/// Generates a non-zero macro for a custom integer type.
+///
+/// The generated macro has the same name and arguments as the integer type
+/// macros of the crate (e.g. [`nz::u8!`][`crate::u8`]), but it creates a
+/// [`NonZero<T>`][`core::num::NonZero`] of the given type. The type must be a
+/// type alias of a primitive integer type, because the
+/// [`ZeroablePrimitive`][`core::num::ZeroablePrimitive`] trait required by
+/// [`NonZero<T>`][`core::num::NonZero`] is sealed and only implemented for
+/// them. The macro is defined where the generator is invoked and the type must
+/// be in scope wherever the generated macro is used.
+///
+/// Attributes before the name of the type are applied to the generated macro.
+///
+/// # Examples
+///
+/// #### For type alias
+/// ```rust
+/// # use std::num::NonZero;
+/// type Port = u16;
+///
+/// nz::gen_nz_macro!(
+///     /// Creates a non-zero port number.
+///     Port
+/// );
+///
+/// const HTTP: NonZero<Port> = Port!(80);
+/// let alt = Port!(HTTP.get() + 8000, "port must be non-zero");
+/// # assert_eq!(80, HTTP.get());
+/// # assert_eq!(8080, alt.get());
+/// ```
+///
+/// #### Zero fails to compile
+/// ```rust, compile_fail
+/// type Port = u16;
+/// nz::gen_nz_macro!(Port);
+/// let _ = Port!(0);
+/// ```
+#[macro_export]
+macro_rules! gen_nz_macro {
+    ($(#[$attr:meta])* $int_type:ident) => {
+        $crate::__gen_nz_macro!(($) $(#[$attr])* $int_type);
+    };
+}
+
+/// Generates the non-zero macro of [`gen_nz_macro`], with the `$` token passed
+/// as the first argument for the metavariables of the generated macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __gen_nz_macro {
+    (($d:tt) $(#[$attr:meta])* $int_type:ident) => {
+        $(#[$attr])*
+        macro_rules! $int_type {
+            ($d int_expr:expr) => {
+                $int_type!(
+                    $d int_expr,
+                    concat!(
+                        stringify!($int_type), "!: expected non-zero ",
+                        stringify!($int_type), " value"
+                    )
+                )
+            };
+            ($d int_expr:expr, $d message:expr) => {
+                const {
+                    let int_value: $int_type = $d int_expr;
+                    match core::num::NonZero::<$int_type>::new(int_value) {
+                        Some(non_zero) => non_zero,
+                        None => panic!("{}", $d message),
+                    }
+                }
+            };
+        }
+    };
+}
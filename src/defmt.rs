@@ -0,0 +1,87 @@
+//! Macros that help formatting [`NonZero`][`core::num::NonZero`] values with
+//! `defmt`.
+
+/// Generates a `defmt` wrapper type macro for the specified integer type.
+macro_rules! gen_nz_defmt_macro {
+    ($d:tt $macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Declares a wrapper type of [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] that implements `defmt::Format`.")]
+        #[doc = r""]
+        /// The argument is the name of the type, which can be preceded by
+        /// attributes and a visibility. The declared tuple struct has a single
+        /// public field and it can be created with [`From`]. Its `defmt::Format`
+        /// implementation writes the primitive value with `defmt::write!`.
+        ///
+        /// Available on crate feature `defmt` only. The `defmt` crate must be a
+        /// dependency of the crate that invokes the macro.
+        ///
+        /// # Examples
+        ///
+        /// #### Wrapper type
+        /// ```rust
+        /// # mod defmt {
+        /// #     pub struct Formatter<'a>(pub &'a mut String);
+        /// #     pub trait Format {
+        /// #         fn format(&self, fmt: Formatter<'_>);
+        /// #     }
+        /// #     macro_rules! write {
+        /// #         ($fmt:expr, $format:literal, $value:expr) => { $fmt.0.push_str(&$value.to_string()) };
+        /// #     }
+        /// #     pub(crate) use write;
+        /// # }
+        /// use defmt::Format;
+        ///
+        #[doc = concat!(" nz::", stringify!($macro_name), "!(pub(crate) Id);")]
+        ///
+        #[doc = concat!(" let id = Id::from(nz::", stringify!($int_type), "!(42));")]
+        /// // defmt::info!("id: {}", id);
+        /// # let mut output = String::new();
+        /// # id.format(defmt::Formatter(&mut output));
+        /// # assert_eq!("42", output);
+        /// # assert_eq!(42, id.0.get());
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($d(#[$d attr:meta])* $d vis:vis $d type_name:ident) => {
+                $d(#[$d attr])*
+                #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+                $d vis struct $d type_name(pub core::num::NonZero<$int_type>);
+
+                impl core::convert::From<core::num::NonZero<$int_type>> for $d type_name {
+                    fn from(non_zero: core::num::NonZero<$int_type>) -> Self {
+                        Self(non_zero)
+                    }
+                }
+
+                impl defmt::Format for $d type_name {
+                    fn format(&self, fmt: defmt::Formatter<'_>) {
+                        defmt::write!(fmt, "{}", self.0.get())
+                    }
+                }
+            };
+        }
+    };
+}
+
+/// Generates a `defmt` wrapper type macro from each macro name and integer type
+/// pair.
+macro_rules! gen_nz_defmt_macros {
+    ($d:tt $($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_defmt_macro!($d $macro_name, $int_type);)*
+    };
+}
+
+gen_nz_defmt_macros!(
+    $
+    defmt_i8: i8,
+    defmt_i16: i16,
+    defmt_i32: i32,
+    defmt_i64: i64,
+    defmt_i128: i128,
+    defmt_isize: isize,
+    defmt_u8: u8,
+    defmt_u16: u16,
+    defmt_u32: u32,
+    defmt_u64: u64,
+    defmt_u128: u128,
+    defmt_usize: usize
+);
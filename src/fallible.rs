@@ -0,0 +1,288 @@
+//! Macros that create a [`NonZero`][`core::num::NonZero`] without failing to
+//! compile on zero.
+
+/// Generates a fallible non-zero macro for the specified integer type.
+macro_rules! gen_try_nz_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates an [`Option<NonZero<", stringify!($int_type), ">>`][`core::num::NonZero`] from an")]
+        #[doc = concat!("expression that evaluates to [`prim@", stringify!($int_type), "`].")]
+        #[doc = r""]
+        #[doc = concat!("The expansion is [`NonZero::<", stringify!($int_type), ">::new`][`core::num::NonZero::new`],")]
+        /// so [`None`] is returned for zero instead of a compile-time [`panic`].
+        /// Unlike the panicking macros, the argument is not required to be a
+        /// constant expression, therefore it can be used in both constant and
+        /// non-constant contexts.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant expression
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: Option<NonZero<", stringify!($int_type), ">> = nz::", stringify!($macro_name), "!(0b1100 & 0b0110);")]
+        #[doc = concat!(" const ZERO: Option<NonZero<", stringify!($int_type), ">> = nz::", stringify!($macro_name), "!(0);")]
+        /// # assert_eq!(Some(0b0100), NZ.map(NonZero::get));
+        /// # assert_eq!(None, ZERO);
+        /// ```
+        ///
+        /// #### From non-constant expression
+        /// ```rust
+        #[doc = concat!(" fn parse(s: &str) -> ", stringify!($int_type), " { s.parse().unwrap_or(0) }")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(parse(\"27\"));")]
+        #[doc = concat!(" let zero = nz::", stringify!($macro_name), "!(parse(\"zero\"));")]
+        /// # assert_eq!(Some(27), nz.map(|nz| nz.get()));
+        /// # assert_eq!(None, zero);
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {
+                core::num::NonZero::<$int_type>::new($int_expr)
+            };
+        }
+    };
+}
+
+/// Generates a fallible non-zero macro from each macro name and integer type
+/// pair.
+macro_rules! gen_try_nz_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_try_nz_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_try_nz_macros!(
+    try_i8: i8,
+    try_i16: i16,
+    try_i32: i32,
+    try_i64: i64,
+    try_i128: i128,
+    try_isize: isize,
+    try_u8: u8,
+    try_u16: u16,
+    try_u32: u32,
+    try_u64: u64,
+    try_u128: u128,
+    try_usize: usize
+);
+
+/// Generates a non-zero macro with fallback value for the specified integer
+/// type.
+macro_rules! gen_nz_or_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] from an expression")]
+        #[doc = concat!("that evaluates to [`prim@", stringify!($int_type), "`] or uses the fallback value if it is zero.")]
+        #[doc = r""]
+        /// The first argument is not required to be a constant expression,
+        /// however the fallback value is created with the matching panicking
+        #[doc = concat!("macro ([`nz::", stringify!($int_type), "!`][`crate::", stringify!($int_type), "`]), so it has to be a literal, a constant value")]
+        /// or expression and a zero fallback value fails to compile.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant expression
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(0b1100 & 0b0110, 1);")]
+        #[doc = concat!(" const FALLBACK: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(0, 1);")]
+        /// # assert_eq!(0b0100, NZ.get());
+        /// # assert_eq!(1, FALLBACK.get());
+        /// ```
+        ///
+        /// #### From non-constant expression
+        /// ```rust
+        #[doc = concat!(" fn parse(s: &str) -> ", stringify!($int_type), " { s.parse().unwrap_or(0) }")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(parse(\"27\"), 8);")]
+        #[doc = concat!(" let fallback = nz::", stringify!($macro_name), "!(parse(\"zero\"), 8);")]
+        /// # assert_eq!(27, nz.get());
+        /// # assert_eq!(8, fallback.get());
+        /// ```
+        ///
+        /// #### Zero fallback value fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(1, 0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr, $fallback_expr:expr) => {
+                match core::num::NonZero::<$int_type>::new($int_expr) {
+                    Some(non_zero) => non_zero,
+                    None => $crate::$int_type!($fallback_expr),
+                }
+            };
+        }
+    };
+}
+
+/// Generates a non-zero macro with fallback value from each macro name and
+/// integer type pair.
+macro_rules! gen_nz_or_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_or_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_or_macros!(
+    i8_or: i8,
+    i16_or: i16,
+    i32_or: i32,
+    i64_or: i64,
+    i128_or: i128,
+    isize_or: isize,
+    u8_or: u8,
+    u16_or: u16,
+    u32_or: u32,
+    u64_or: u64,
+    u128_or: u128,
+    usize_or: usize
+);
+
+/// Generates a constant function and a macro that substitute the maximum value
+/// for zero for the specified unsigned integer type.
+macro_rules! gen_nz_or_max {
+    ($name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] from a [`prim@", stringify!($int_type), "`] or uses")]
+        #[doc = concat!("[`", stringify!($int_type), "::MAX`] if it is zero.")]
+        #[doc = r""]
+        /// The conversion is total, so it never panics and it can be used with
+        #[doc = concat!("runtime values. This is the function counterpart of [`nz::", stringify!($name), "!`][`crate::", stringify!($name), "!`].")]
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($name), "(0);")]
+        #[doc = concat!(" let nz = nz::", stringify!($name), "(\"27\".parse().unwrap());")]
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::MAX, NZ.get());")]
+        /// # assert_eq!(27, nz.get());
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn $name(value: $int_type) -> core::num::NonZero<$int_type> {
+            match core::num::NonZero::<$int_type>::new(value) {
+                Some(non_zero) => non_zero,
+                None => core::num::NonZero::<$int_type>::MAX,
+            }
+        }
+
+        #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] from an expression that evaluates to")]
+        #[doc = concat!("[`prim@", stringify!($int_type), "`] or uses [`", stringify!($int_type), "::MAX`] if it is zero.")]
+        #[doc = r""]
+        /// This matches the semantics of hardware registers that treat zero as
+        /// the maximum value. The conversion is total, so it never fails and
+        /// the argument is not required to be a constant expression. It expands
+        #[doc = concat!("to a call of the [`nz::", stringify!($name), "`][`crate::", stringify!($name), "()`] constant function, so it can also be")]
+        /// used in constant contexts.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant expression
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($name), "!(0b1100 & 0b0110);")]
+        #[doc = concat!(" const MAX: NonZero<", stringify!($int_type), "> = nz::", stringify!($name), "!(0);")]
+        /// # assert_eq!(0b0100, NZ.get());
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::MAX, MAX.get());")]
+        /// ```
+        ///
+        /// #### From non-constant expression
+        /// ```rust
+        #[doc = concat!(" fn read_register() -> ", stringify!($int_type), " { 0 }")]
+        #[doc = concat!(" let nz = nz::", stringify!($name), "!(read_register());")]
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::MAX, nz.get());")]
+        /// ```
+        #[macro_export]
+        macro_rules! $name {
+            ($int_expr:expr) => {
+                $crate::$name($int_expr)
+            };
+        }
+    };
+}
+
+/// Generates a constant function and a macro that substitute the maximum value
+/// for zero from each name and unsigned integer type pair.
+macro_rules! gen_nz_or_maxs {
+    ($($name:ident: $int_type:ident), *) => {
+        $(gen_nz_or_max!($name, $int_type);)*
+    };
+}
+
+gen_nz_or_maxs!(
+    nonzero_or_max_u8: u8,
+    nonzero_or_max_u16: u16,
+    nonzero_or_max_u32: u32,
+    nonzero_or_max_u64: u64,
+    nonzero_or_max_u128: u128,
+    nonzero_or_max_usize: usize
+);
+
+/// Generates a macro that selects the first non-zero value for the specified
+/// integer type.
+macro_rules! gen_nz_first_nonzero_macro {
+    ($d:tt $macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Selects the first non-zero value of literals, constant values or expressions that evaluate to")]
+        #[doc = concat!("[`prim@", stringify!($int_type), "`] as a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        /// The candidates are checked in order and they can be zero. The last
+        /// argument is the default value, which is used if all of the
+        /// candidates are zero, so it is required to be non-zero and the result
+        /// never has to be checked. If the default value evaluates to zero, a
+        /// [`panic`] will occur at compile time. A trailing comma is accepted.
+        ///
+        /// # Examples
+        ///
+        /// #### From constant values
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const CONFIGURED: ", stringify!($int_type), " = 0;")]
+        #[doc = concat!(" const DETECTED: ", stringify!($int_type), " = 8;")]
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(CONFIGURED, DETECTED, 4);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(CONFIGURED, 0, 4,);")]
+        /// # assert_eq!(8, NZ.get());
+        /// # assert_eq!(4, nz.get());
+        /// ```
+        ///
+        /// #### Zero default value fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(1, 0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            (@select $d default_expr:expr) => {
+                $crate::__nz_operand!($macro_name, $int_type, $d default_expr)
+            };
+            (@select $d candidate_expr:expr, $d($d rest_expr:expr),+) => {
+                match core::num::NonZero::<$int_type>::new($d candidate_expr) {
+                    Some(non_zero) => non_zero,
+                    None => $crate::$macro_name!(@select $d($d rest_expr),+),
+                }
+            };
+            ($d($d int_expr:expr),+ $d(,)?) => {
+                const { $crate::$macro_name!(@select $d($d int_expr),+) }
+            };
+        }
+    };
+}
+
+/// Generates a macro that selects the first non-zero value from each macro name
+/// and integer type pair.
+macro_rules! gen_nz_first_nonzero_macros {
+    ($d:tt $($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_first_nonzero_macro!($d $macro_name, $int_type);)*
+    };
+}
+
+gen_nz_first_nonzero_macros!(
+    $
+    first_nonzero_i8: i8,
+    first_nonzero_i16: i16,
+    first_nonzero_i32: i32,
+    first_nonzero_i64: i64,
+    first_nonzero_i128: i128,
+    first_nonzero_isize: isize,
+    first_nonzero_u8: u8,
+    first_nonzero_u16: u16,
+    first_nonzero_u32: u32,
+    first_nonzero_u64: u64,
+    first_nonzero_u128: u128,
+    first_nonzero_usize: usize
+);
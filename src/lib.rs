@@ -26,6 +26,26 @@
 //! * `no_std` compatible
 //! * Supports every type that implements the [`ZeroablePrimitive`][`core::num::ZeroablePrimitive`] marker trait
 //! * Compile-time evaluation
+//! * Works on the `stable` toolchain
+//!
+//! ## Toolchain
+//!
+//! The macros expand to inline constant expressions (`const { ... }`) of the
+//! generic [`NonZero`][`core::num::NonZero`] type. Both of them are stable
+//! since Rust `1.79.0`, which is the `MSRV` of the crate, so neither the
+//! `nightly` toolchain nor the `inline_const` and `generic_nonzero` features
+//! are required.
+//!
+//! ## Crate features
+//!
+//! The macros do not depend on the crates that they integrate with, so none of
+//! the features add dependencies. All of them are disabled by default.
+//!
+//! * `arbitrary`: macros that create non-zero values from `arbitrary::Unstructured`
+//! * `defmt`: macros that declare wrapper types that implement `defmt::Format`
+//! * `link-section`: macros that declare statics placed in a link section
+//! * `num-traits`: macros that create the non-zero multiplicative identity
+//! * `serde`: macros that declare default value functions for `serde`
 //!
 //! ## Macros
 //!
@@ -43,6 +63,113 @@
 //! | [`NonZero<u64>`][`core::num::NonZeroU64`] | [`nz::u64!`][`crate::u64`] |
 //! | [`NonZero<u128>`][`core::num::NonZeroU128`] | [`nz::u128!`][`crate::u128`] |
 //! | [`NonZero<usize>`][`core::num::NonZeroUsize`] | [`nz::usize!`][`crate::usize`] |
+//! | [`NonZero<T>`][`core::num::NonZero`] (inferred) | [`nz::non_zero!`][`crate::non_zero`] |
+//! | [`NonZero<T>`][`core::num::NonZero`] (explicit) | [`nz::of!`][`crate::of`] |
+//! | [`NonZero<T>`][`core::num::NonZero`] (annotated) | [`nz::typed!`][`crate::typed`] |
+//! | [`NonZero<T>`][`core::num::NonZero`] (from literal suffix) | [`nz::lit!`][`crate::lit`] |
+//! | [`NonZero<T>`][`core::num::NonZero`] (widened) | [`nz::cast!`][`crate::cast`] |
+//! | [`NonZero<T>`][`core::num::NonZero`] (truncated) | [`nz::cast_truncating!`][`crate::cast_truncating`] |
+//! | [`NonZero<T>`][`core::num::NonZero`] (from `char`) | [`nz::from_char!`][`crate::from_char`] |
+//! | [`NonZero<isize>`][`core::num::NonZeroIsize`] (pointer width checked) | [`nz::isize_for!`][`crate::isize_for`] |
+//! | [`NonZero<usize>`][`core::num::NonZeroUsize`] (pointer width checked) | [`nz::usize_for!`][`crate::usize_for`] |
+//! | [`NonZero<u64>`][`core::num::NonZeroU64`] (seconds) | [`nz::secs!`][`crate::secs`] |
+//! | [`NonZero<u64>`][`core::num::NonZeroU64`] (milliseconds) | [`nz::millis!`][`crate::millis`] |
+//! | [`NonZero<u64>`][`core::num::NonZeroU64`] (microseconds) | [`nz::micros!`][`crate::micros`] |
+//! | [`NonZero<T>`][`core::num::NonZero`] (custom integer type) | [`nz::gen_nz_macro!`][`crate::gen_nz_macro`] |
+//! | [`NonZero<T>`][`core::num::NonZero`] (checked conversion) | [`nz::from_nonzero!`][`crate::from_nonzero`] |
+//! | [`NonZero<T>`][`core::num::NonZero`] (fallible conversion) | [`nz::try_from_nonzero!`][`crate::try_from_nonzero`] |
+//! | `[T; N]` (non-zero length) | [`nz::array_len!`][`crate::array_len`] |
+//! | `[NonZero<u8>; N]` (from byte string) | [`nz::bytes_nonzero!`][`crate::bytes_nonzero`] |
+//! | [`NonZero<usize>`][`core::num::NonZeroUsize`] (aligned up) | [`nz::align_up_usize!`][`crate::align_up_usize`] |
+//! | [`NonZero<usize>`][`core::num::NonZeroUsize`] and [`Range<usize>`][`core::ops::Range`] | [`nz::range_len_usize!`][`crate::range_len_usize`] |
+//!
+//! The following macros are also provided for the integer types, where
+//! `{int}` stands for the name of the integer type (e.g. `nz::try_u8!`):
+//!
+//! | Macro | Description |
+//! |-------|-------------|
+//! | `nz::try_{int}!` | Creates an `Option<NonZero<{int}>>`, returning `None` for zero |
+//! | `nz::{int}_or!` | Creates a `NonZero<{int}>`, using a compile-time checked fallback value for zero |
+//! | `nz::{int}_array!` | Creates a `[NonZero<{int}>; N]` from a list of elements or a repeated element |
+//! | `nz::gcd_{int}!` | Computes the greatest common divisor of non-zero values (unsigned only) |
+//! | `nz::mul_{int}!` | Multiplies non-zero values without checking the product for zero |
+//! | `nz::pow_{int}!` | Raises a non-zero value to a power without checking the result for zero |
+//! | `nz::from_str_radix_{int}!` | Parses a constant string of the given radix (unsigned only) |
+//! | `nz::env_{int}!` | Parses the decimal value of a compile-time environment variable (unsigned only) |
+//! | `nz::min_{int}!` | Returns the minimum of two or more non-zero values, or the type's non-zero minimum |
+//! | `nz::max_{int}!` | Returns the maximum of two or more non-zero values, or the type's non-zero maximum |
+//! | `nz::clamp_{int}!` | Restricts a non-zero value to a non-zero interval |
+//! | `nz::bool_{int}!` | Converts a constant `bool` to `1` or selects one of two non-zero values |
+//! | `nz::abs_{int}!` | Computes the absolute value of a non-zero value (signed only) |
+//! | `nz::unsigned_abs_{int}!` | Computes the unsigned absolute value of a non-zero value (signed only) |
+//! | `nz::assert_nz_{int}!` | Asserts that a value is non-zero at compile time without creating a `NonZero` |
+//! | `nz::nz_{int}` | Function counterpart of `nz::{int}!` that panics on zero |
+//! | `nz::checked_add_{int}!` | Adds non-zero values, returning `None` on overflow (unsigned only) |
+//! | `nz::checked_sub_{int}!` | Subtracts non-zero values, returning `None` on overflow or zero |
+//! | `nz::checked_mul_{int}!` | Multiplies non-zero values, returning `None` on overflow |
+//! | `nz::saturating_add_{int}!` | Adds non-zero values, saturating at the numeric bounds (unsigned only) |
+//! | `nz::saturating_mul_{int}!` | Multiplies non-zero values, saturating at the numeric bounds |
+//! | `nz::next_power_of_two_{int}!` | Rounds a non-zero value up to the next power of two (unsigned only) |
+//! | `nz::ilog2_{int}!` | Computes the base 2 logarithm of a non-zero value as `u32` (unsigned only) |
+//! | `nz::ilog10_{int}!` | Computes the base 10 logarithm of a non-zero value as `u32` (unsigned only) |
+//! | `nz::serde_default_{int}!` | Declares a default value function for `serde` (`serde` feature) |
+//! | `nz::arbitrary_{int}!` | Creates a `NonZero<{int}>` from `arbitrary::Unstructured` with a fallback value (`arbitrary` feature) |
+//! | `nz::one_{int}!` | Creates a `NonZero<{int}>` of one, `nz::one!` infers the type (`num-traits` feature) |
+//! | `nz::{int}_in!` | Creates a `NonZero<{int}>` that is checked against an inclusive range |
+//! | `nz::with_raw_{int}!` | Creates a `(NonZero<{int}>, {int})` tuple from a single expression |
+//! | `nz::{int}_array_try!` | Creates an `[Option<NonZero<{int}>>; N]` array without failing on zero elements |
+//! | `nz::sum_{int}!` | Adds one or more non-zero values with overflow checking (unsigned only) |
+//! | `nz::product_{int}!` | Multiplies one or more non-zero values with overflow checking |
+//! | `nz::from_be_bytes_{int}!` | Creates a non-zero value from a constant big endian byte array |
+//! | `nz::from_le_bytes_{int}!` | Creates a non-zero value from a constant little endian byte array |
+//! | `nz::bit_{int}!` | Creates a single-bit mask with overflow checking (unsigned only) |
+//! | `nz::low_mask_{int}!` | Creates a mask of the lowest `k` bits for a non-zero width (unsigned only) |
+//! | `nz::rem_euclid_{int}!` | Computes the Euclidean remainder by a non-zero divisor as `{int}` (signed only) |
+//! | `nz::div_exact_{int}!` | Divides non-zero values, failing if the division is not exact (unsigned only) |
+//! | `nz::map_{int}!` | Maps a non-zero value with a constant transformation and checks the result |
+//! | `nz::with_popcount_{int}!` | Creates a non-zero value with the specified number of set bits |
+//! | `nz::nonzero_or_max_{int}!` | Creates a non-zero value, using `{int}::MAX` for zero (unsigned only) |
+//! | `nz::debug_{int}!` | Creates a non-zero value with the source location in the panic message |
+//! | `nz::hex_{int}!` | Creates a non-zero value from hexadecimal digits without prefix (unsigned only) |
+//! | `nz::oct_{int}!` | Creates a non-zero value from octal digits without prefix (unsigned only) |
+//! | `nz::bin_{int}!` | Creates a non-zero value from binary digits without prefix (unsigned only) |
+//! | `nz::swap_bytes_{int}!` | Reverses the byte order of a non-zero value |
+//! | `nz::from_discriminant_{int}!` | Creates a non-zero value from the discriminant of a fieldless enum variant |
+//! | `nz::with_leading_zeros_{int}!` | Creates a `(NonZero<{int}>, u32)` tuple of a value and its number of leading zeros |
+//! | `nz::defmt_{int}!` | Declares a wrapper type of `NonZero<{int}>` that implements `defmt::Format` (`defmt` feature) |
+//! | `nz::max_of_slice_{int}!` | Computes the non-zero maximum of a constant slice |
+//! | `nz::rotate_left_{int}!` | Rotates the bits of a non-zero value to the left |
+//! | `nz::rotate_right_{int}!` | Rotates the bits of a non-zero value to the right |
+//! | `nz::reverse_bits_{int}!` | Reverses the bit order of a non-zero value |
+//! | `nz::from_ratio_{int}!` | Computes the rounded ratio `a * num / den` with a non-zero result (unsigned only, up to 64 bits) |
+//! | `nz::first_nonzero_{int}!` | Selects the first non-zero value or the non-zero default value |
+//! | `nz::to_str_{int}!` | Formats a non-zero value into a decimal `&'static str` at compile time |
+//! | `nz::pow2_{int}!` | Creates a non-zero value that is checked to be a power of two (unsigned only) |
+//! | `nz::wrapping_neg_{int}!` | Computes the wrapping negation of a non-zero value (signed only) |
+//! | `nz::consts_{int}!` | Declares multiple `NonZero<{int}>` constant items |
+//! | `nz::scale_{int}!` | Scales a value by `num / den` using a `u128` intermediate (unsigned only, up to 64 bits) |
+//! | `nz::checked_pow_{int}!` | Raises a value to a power, returning `None` for a zero base or on overflow |
+//! | `nz::select_{int}!` | Selects one of two non-zero values based on a constant condition |
+//! | `nz::wrapping_add_{int}!` | Adds non-zero values with wrapping, failing if the sum wraps to zero (unsigned only) |
+//! | `nz::assert_eq_{int}!` | Asserts that two non-zero values are equal at compile time |
+//! | `nz::no_zero_digits_{int}!` | Creates a non-zero value without any `0` decimal digit (unsigned only) |
+//! | `nz::repeat_byte_{int}!` | Repeats a non-zero byte in every byte of a wider unsigned value |
+//! | `nz::checked_next_multiple_of_{int}!` | Rounds a value up to a multiple, returning `None` for zero or on overflow (unsigned only) |
+//! | `nz::assert_fits_{int}!` | Asserts that a non-zero value fits into the integer type at compile time |
+//! | `nz::saturating_sub_to_one_{int}!` | Subtracts non-zero values, saturating at one (unsigned only) |
+//! | `nz::to_be_bytes_{int}!` | Creates the big endian byte array of a non-zero value |
+//! | `nz::to_le_bytes_{int}!` | Creates the little endian byte array of a non-zero value |
+//! | `nz::signum_{int}!` | Computes the sign of a non-zero value (signed only) |
+//! | `nz::lcm_{int}!` | Computes the least common multiple of non-zero values (unsigned only) |
+//! | `nz::parse_{int}!` | Parses a constant decimal string (unsigned only) |
+//! | `nz::validate_all_{int}!` | Converts a constant array to an array of non-zero values |
+//! | `nz::section_{int}!` | Declares a non-zero static placed in a link section (`link-section` feature) |
+//! | `nz::try_into_{int}!` | Converts a non-zero value of another type, returning a `Result` |
+//! | `nz::checked_shl_{int}!` | Shifts a non-zero value left, returning `None` if the shift overflows or results in zero (unsigned only) |
+//! | `nz::from_index_{int}!` | Creates a one-based non-zero value from a zero-based index (unsigned only) |
+//! | `nz::to_index_{int}!` | Converts a one-based non-zero value to a zero-based index (unsigned only) |
+//! | `nz::bounded_{int}!` | Declares a wrapper type of `NonZero<{int}>` restricted to an inclusive range |
+//! | `nz::checked_div_{int}!` | Divides by a non-zero divisor, returning `None` if the quotient is zero (unsigned only) |
 //!
 //! ## Usage
 //!
@@ -71,12 +198,49 @@
 //! # assert_eq!(9, RES.get());
 //! # assert_eq!(8, res.get());
 //! ```
+//!
+//! The macros can also be used without the `nz::` prefix by glob importing
+//! the [`prelude`] module with `use nz::prelude::*;`.
 #![no_std]
 #![forbid(unsafe_code)]
 
-/// Generates a non-zero macro for the specified integer type.
-macro_rules! gen_nz_macro {
-    ($int_type:ident) => {
+#[doc(hidden)]
+pub mod __private;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod arith;
+mod array;
+mod assert;
+mod bits;
+mod bounded;
+mod checked;
+mod cmp;
+mod consts;
+mod convert;
+mod custom;
+#[cfg(feature = "defmt")]
+mod defmt;
+mod fallible;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+mod parse;
+pub mod prelude;
+mod range;
+#[cfg(feature = "link-section")]
+mod section;
+#[cfg(feature = "serde")]
+mod serde;
+mod time;
+
+pub use fallible::{
+    nonzero_or_max_u128, nonzero_or_max_u16, nonzero_or_max_u32,
+    nonzero_or_max_u64, nonzero_or_max_u8, nonzero_or_max_usize,
+};
+
+/// Generates a non-zero macro for the specified integer type, with examples
+/// for the minimum value if it is given.
+macro_rules! gen_int_nz_macro {
+    ($int_type:ident $(, $min_literal:literal)?) => {
         #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] from a")]
         #[doc = r"literal, a constant value or expression that evaluates"]
         #[doc = concat!("to [`prim@", stringify!($int_type), "`].")]
@@ -84,6 +248,11 @@ macro_rules! gen_nz_macro {
         #[doc = concat!("If the argument cannot be evaluated to a [`prim@", stringify!($int_type), "`],")]
         /// a will occur [`panic`] at compile time.
         ///
+        /// An optional second argument can be used to replace the default panic
+        /// message, which must be a string literal or a constant `&'static str`.
+        /// A leading unary `+` sign is accepted and ignored, so the macro can be
+        /// invoked with arguments emitted by other macros that prepend a sign.
+        ///
         /// # Examples
         ///
         /// #### From integer literal
@@ -115,10 +284,40 @@ macro_rules! gen_nz_macro {
         /// # assert_eq!(0b0101, nz.get());
         /// ```
         ///
+        /// #### With custom panic message
+        /// ```rust
+        #[doc = concat!(" const CAPACITY: ", stringify!($int_type), " = 16;")]
+        #[doc = concat!(" let nz = nz::", stringify!($int_type), "!(CAPACITY, \"capacity must be non-zero\");")]
+        /// # assert_eq!(CAPACITY, nz.get());
+        /// ```
+        ///
+        /// #### With leading plus sign
+        /// ```rust
+        #[doc = concat!(" let nz = nz::", stringify!($int_type), "!(+5);")]
+        #[doc = concat!(" # assert_eq!(nz::", stringify!($int_type), "!(5), nz);")]
+        /// ```
+        ///
         /// #### Zero literal fails to compile
         /// ```rust, compile_fail
         #[doc = concat!(" let _ = nz::", stringify!($int_type), "!(0);")]
         /// ```
+        $(
+        ///
+        /// #### From minimum value
+        /// The full negative range is accepted, including the minimum value.
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ_MIN: NonZero<", stringify!($int_type), "> = nz::", stringify!($int_type), "!(", $min_literal, ");")]
+        #[doc = concat!(" let nz = nz::", stringify!($int_type), "!(", stringify!($int_type), "::MIN + 1);")]
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::MIN, NZ_MIN.get());")]
+        #[doc = concat!(" # assert_eq!(", stringify!($int_type), "::MIN + 1, nz.get());")]
+        /// ```
+        ///
+        /// #### Negative zero literal fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($int_type), "!(-0);")]
+        /// ```
+        )?
         ///
         /// #### Non-constant expression fails to compile
         /// ```rust, compile_fail
@@ -133,13 +332,41 @@ macro_rules! gen_nz_macro {
         /// ```rust, compile_fail
         #[doc = concat!(" let _ = nz::", stringify!($int_type), "!(0x02 - 0b0010);")]
         /// ```
+        ///
+        /// #### Overflowing constant expression fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($int_type), "!(", stringify!($int_type), "::MAX + 1);")]
+        /// ```
+        ///
+        /// #### Argument of other type fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($int_type), "!(true);")]
+        /// ```
         #[macro_export]
         macro_rules! $int_type {
-            ($int_expr:expr) => {{
+            (+ $int_expr:expr) => {
+                $crate::$int_type!($int_expr)
+            };
+            (+ $int_expr:expr, $message:expr) => {
+                $crate::$int_type!($int_expr, $message)
+            };
+            ($int_expr:expr) => {
+                $crate::$int_type!(
+                    $int_expr,
+                    concat!(
+                        "nz::", stringify!($int_type), "!: expected non-zero ",
+                        stringify!($int_type), " value"
+                    )
+                )
+            };
+            ($int_expr:expr, $message:expr) => {{
                 const {{
-                    match core::num::NonZero::<$int_type>::new($int_expr) {
+                    // Binding the argument first ties diagnostics about it
+                    // to the call site instead of the body of the macro.
+                    let int_value: $int_type = $int_expr;
+                    match core::num::NonZero::<$int_type>::new(int_value) {
                         Some(non_zero) => non_zero,
-                        None => panic!("expected a non-zero value"),
+                        None => panic!("{}", $message),
                     }
                 }}
             }};
@@ -147,11 +374,399 @@ macro_rules! gen_nz_macro {
     };
 }
 
-/// Generates a non-zero macro from each identifier.
-macro_rules! gen_nz_macros {
-    ($($int_type:ident), *) => {
-        $(gen_nz_macro!($int_type);)*
+/// Generates a non-zero macro from each identifier and optional minimum value
+/// of the signed integer types.
+macro_rules! gen_int_nz_macros {
+    ($($int_type:ident $(= $min_literal:literal)?), *) => {
+        $(gen_int_nz_macro!($int_type $(, $min_literal)?);)*
     };
 }
 
-gen_nz_macros!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+gen_int_nz_macros!(
+    i8 = "-128",
+    i16 = "-32_768",
+    i32 = "-2_147_483_648",
+    i64 = "-9_223_372_036_854_775_808",
+    i128 = "-170_141_183_460_469_231_731_687_303_715_884_105_728",
+    isize = "isize::MIN",
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize
+);
+
+/// Generates a non-zero constant function for the specified integer type.
+macro_rules! gen_nz_fn {
+    ($fn_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] from a [`prim@", stringify!($int_type), "`].")]
+        #[doc = r""]
+        #[doc = concat!("This is the function counterpart of [`nz::", stringify!($int_type), "!`][`crate::", stringify!($int_type), "`] for contexts where")]
+        /// a function is needed instead of a macro (e.g. `array.map(...)`).
+        /// When it is evaluated at compile time, a zero value results in a
+        /// [`panic`] at compile time, otherwise it panics at runtime. The panic
+        /// message is the same as the macro's.
+        ///
+        /// # Examples
+        ///
+        /// #### In constant context
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($fn_name), "(0x10);")]
+        /// # assert_eq!(0x10, NZ.get());
+        /// ```
+        ///
+        /// #### As function argument
+        /// ```rust
+        #[doc = concat!(" let nzs = [1, 2, 3].map(nz::", stringify!($fn_name), ");")]
+        /// # assert_eq!([1, 2, 3], nzs.map(|nz| nz.get()));
+        /// ```
+        ///
+        /// #### Zero fails to compile in constant context
+        /// ```rust, compile_fail
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const _: NonZero<", stringify!($int_type), "> = nz::", stringify!($fn_name), "(0);")]
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// Panics if the value is zero.
+        #[must_use]
+        #[inline]
+        pub const fn $fn_name(value: $int_type) -> core::num::NonZero<$int_type> {
+            match core::num::NonZero::<$int_type>::new(value) {
+                Some(non_zero) => non_zero,
+                None => panic!(concat!(
+                    "nz::", stringify!($int_type), "!: expected non-zero ",
+                    stringify!($int_type), " value"
+                )),
+            }
+        }
+    };
+}
+
+/// Generates a non-zero constant function from each function name and
+/// integer type pair.
+macro_rules! gen_nz_fns {
+    ($($fn_name:ident: $int_type:ident), *) => {
+        $(gen_nz_fn!($fn_name, $int_type);)*
+    };
+}
+
+gen_nz_fns!(
+    nz_i8: i8,
+    nz_i16: i16,
+    nz_i32: i32,
+    nz_i64: i64,
+    nz_i128: i128,
+    nz_isize: isize,
+    nz_u8: u8,
+    nz_u16: u16,
+    nz_u32: u32,
+    nz_u64: u64,
+    nz_u128: u128,
+    nz_usize: usize
+);
+
+/// Creates a [`NonZero`][`core::num::NonZero`] from an operand of another
+/// macro with a panic message that contains the name of the macro and the
+/// source of the operand.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __nz_operand {
+    ($macro_name:ident, $int_type:ident, $int_expr:expr) => {
+        $crate::$int_type!(
+            $int_expr,
+            concat!(
+                "nz::",
+                stringify!($macro_name),
+                "!: expected non-zero ",
+                stringify!($int_type),
+                " operand `",
+                stringify!($int_expr),
+                "`"
+            )
+        )
+    };
+}
+
+/// Creates a [`NonZero`][`core::num::NonZero`] from a literal, a constant value
+/// or expression whose integer type is inferred from the context.
+///
+/// The expansion is identical to the typed macros (e.g. [`nz::u8!`][`crate::u8`])
+/// except that the integer type is left for the compiler to infer, so it is
+/// picked up from a type annotation, a function's return type or a suffixed
+/// literal. Unsuffixed integer literals without any other type information
+/// fall back to [`prim@i32`], just like plain integer literals do.
+///
+/// If the argument evaluates to zero, a [`panic`] will occur at compile time
+/// with the default or the optional custom panic message passed as the second
+/// argument. If the type cannot be inferred, compilation fails with a "type annotations
+/// needed" error, in which case one of the typed macros should be used instead.
+///
+/// # Examples
+///
+/// #### Inferred from type annotation
+/// ```rust
+/// # use std::num::NonZero;
+/// const NZ: NonZero<u16> = nz::non_zero!(0x10);
+/// let nz: NonZero<i64> = nz::non_zero!(-27);
+/// # assert_eq!(0x10, NZ.get());
+/// # assert_eq!(-27, nz.get());
+/// ```
+///
+/// #### Inferred from return type
+/// ```rust
+/// # use std::num::NonZero;
+/// fn capacity() -> NonZero<usize> {
+///     nz::non_zero!(4 * 1024)
+/// }
+/// # assert_eq!(4096, capacity().get());
+/// ```
+///
+/// #### Inferred from literal suffix
+/// ```rust
+/// let nz = nz::non_zero!(200u8);
+/// # assert_eq!(200u8, nz.get());
+/// ```
+///
+/// #### Zero literal fails to compile
+/// ```rust, compile_fail
+/// # use std::num::NonZero;
+/// let _: NonZero<u32> = nz::non_zero!(0);
+/// ```
+///
+/// #### Uninferable type fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::non_zero!(Default::default());
+/// ```
+#[macro_export]
+macro_rules! non_zero {
+    ($int_expr:expr) => {
+        $crate::non_zero!($int_expr, "nz::non_zero!: expected non-zero value")
+    };
+    ($int_expr:expr, $message:expr) => {{
+        const {
+            let int_value = $int_expr;
+            match core::num::NonZero::<_>::new(int_value) {
+                Some(non_zero) => non_zero,
+                None => panic!("{}", $message),
+            }
+        }
+    }};
+}
+
+/// Creates a [`NonZero`][`core::num::NonZero`] of the specified integer type
+/// from a literal, a constant value or expression.
+///
+/// The first argument selects the integer type and the expansion is the same
+/// as the matching typed macro's (e.g. `nz::of!(u8, 1)` is `nz::u8!(1)`).
+/// This is useful for passing the integer type as a parameter of other
+/// macros. In that case, the type must be forwarded as an `ident` or `tt`
+/// fragment, because a `ty` fragment cannot be matched against the supported
+/// type names.
+///
+/// If the type is not one of the supported primitive integer types, a
+/// compilation error is emitted. If the argument evaluates to zero, a
+/// [`panic`] will occur at compile time with the default or the optional
+/// custom panic message passed as the third argument.
+///
+/// # Examples
+///
+/// #### From integer literal
+/// ```rust
+/// # use std::num::NonZero;
+/// const NZ: NonZero<u32> = nz::of!(u32, 5 + 3);
+/// let nz = nz::of!(i8, -27);
+/// # assert_eq!(8, NZ.get());
+/// # assert_eq!(-27, nz.get());
+/// ```
+///
+/// #### From constant value
+/// ```rust
+/// const LEN: usize = 64;
+/// let nz = nz::of!(usize, LEN);
+/// # assert_eq!(LEN, nz.get());
+/// ```
+///
+/// #### Forwarding type from another macro
+/// ```rust
+/// macro_rules! capacity {
+///     ($int_type:ident) => {
+///         nz::of!($int_type, 16)
+///     };
+/// }
+///
+/// let nz_u8 = capacity!(u8);
+/// let nz_u64 = capacity!(u64);
+/// # assert_eq!(16u8, nz_u8.get());
+/// # assert_eq!(16u64, nz_u64.get());
+/// ```
+///
+/// #### Unsupported type fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::of!(f32, 1.0);
+/// ```
+///
+/// #### Zero literal fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::of!(u16, 0);
+/// ```
+#[macro_export]
+macro_rules! of {
+    (i8, $int_expr:expr $(, $message:expr)?) => {
+        $crate::i8!($int_expr $(, $message)?)
+    };
+    (i16, $int_expr:expr $(, $message:expr)?) => {
+        $crate::i16!($int_expr $(, $message)?)
+    };
+    (i32, $int_expr:expr $(, $message:expr)?) => {
+        $crate::i32!($int_expr $(, $message)?)
+    };
+    (i64, $int_expr:expr $(, $message:expr)?) => {
+        $crate::i64!($int_expr $(, $message)?)
+    };
+    (i128, $int_expr:expr $(, $message:expr)?) => {
+        $crate::i128!($int_expr $(, $message)?)
+    };
+    (isize, $int_expr:expr $(, $message:expr)?) => {
+        $crate::isize!($int_expr $(, $message)?)
+    };
+    (u8, $int_expr:expr $(, $message:expr)?) => {
+        $crate::u8!($int_expr $(, $message)?)
+    };
+    (u16, $int_expr:expr $(, $message:expr)?) => {
+        $crate::u16!($int_expr $(, $message)?)
+    };
+    (u32, $int_expr:expr $(, $message:expr)?) => {
+        $crate::u32!($int_expr $(, $message)?)
+    };
+    (u64, $int_expr:expr $(, $message:expr)?) => {
+        $crate::u64!($int_expr $(, $message)?)
+    };
+    (u128, $int_expr:expr $(, $message:expr)?) => {
+        $crate::u128!($int_expr $(, $message)?)
+    };
+    (usize, $int_expr:expr $(, $message:expr)?) => {
+        $crate::usize!($int_expr $(, $message)?)
+    };
+    ($other_type:ty, $int_expr:expr $(, $message:expr)?) => {
+        compile_error!(concat!(
+            "nz::of!: unsupported type `", stringify!($other_type), "`, expected one of ",
+            "`i8`, `i16`, `i32`, `i64`, `i128`, `isize`, `u8`, `u16`, `u32`, `u64`, `u128` or `usize`"
+        ))
+    };
+}
+
+/// Creates a [`NonZero`][`core::num::NonZero`] of the specified integer type
+/// from a literal, a constant value or expression with a type annotation.
+///
+/// The first argument is the integer type, which is used for annotating both
+/// the argument and the [`NonZero`][`core::num::NonZero`] value inside the
+/// constant block. Unlike [`nz::of!`][`crate::of`], the type is matched as a
+/// `ty` fragment, so it can be forwarded from other macros as a type and type
+/// aliases are accepted too. A mismatch between the annotated width and the
+/// context results in a type error that names the annotated type, which helps
+/// to catch width mistakes in large constant tables.
+///
+/// If the argument evaluates to zero, a [`panic`] will occur at compile time
+/// with the default or the optional custom panic message passed as the third
+/// argument.
+///
+/// # Examples
+///
+/// #### From integer literal
+/// ```rust
+/// # use std::num::NonZero;
+/// type Port = u16;
+/// const NZ: NonZero<u16> = nz::typed!(u16, 8080);
+/// let nz = nz::typed!(Port, NZ.get() + 1);
+/// # assert_eq!(8080, NZ.get());
+/// # assert_eq!(8081, nz.get());
+/// ```
+///
+/// #### Mismatching width fails to compile
+/// ```rust, compile_fail
+/// # use std::num::NonZero;
+/// let _: NonZero<u32> = nz::typed!(u16, 8080);
+/// ```
+///
+/// #### Zero literal fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::typed!(u16, 0);
+/// ```
+#[macro_export]
+macro_rules! typed {
+    ($int_type:ty, $int_expr:expr) => {
+        $crate::typed!(
+            $int_type,
+            $int_expr,
+            concat!(
+                "nz::typed!: expected non-zero `",
+                stringify!($int_type),
+                "` value"
+            )
+        )
+    };
+    ($int_type:ty, $int_expr:expr, $message:expr) => {{
+        const {
+            let int_value: $int_type = $int_expr;
+            let non_zero: core::num::NonZero<$int_type> =
+                match core::num::NonZero::new(int_value) {
+                    Some(non_zero) => non_zero,
+                    None => panic!("{}", $message),
+                };
+            non_zero
+        }
+    }};
+}
+
+/// Creates a [`NonZero`][`core::num::NonZero`] from an integer literal whose
+/// type is selected by its suffix.
+///
+/// The type is inferred from the suffix of the literal (e.g. `5u8` results in
+/// a `NonZero<u8>`), so the type does not have to be named twice. If the
+/// literal has no type suffix, compilation fails instead of falling back to
+/// [`prim@i32`] like [`nz::non_zero!`][`crate::non_zero`]. If the literal is
+/// zero, a [`panic`] will occur at compile time.
+///
+/// # Examples
+///
+/// #### From suffixed literals
+/// ```rust
+/// # use std::num::NonZero;
+/// const NZ: NonZero<u8> = nz::lit!(5u8);
+/// let nz = nz::lit!(-0x10_i64);
+/// # assert_eq!(5, NZ.get());
+/// # assert_eq!(-16i64, nz.get());
+/// ```
+///
+/// #### Literal without suffix fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::lit!(5);
+/// ```
+///
+/// #### Zero literal fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::lit!(0u16);
+/// ```
+#[macro_export]
+macro_rules! lit {
+    ($int_literal:literal) => {
+        const {
+            if !$crate::__private::has_int_suffix(stringify!($int_literal)) {
+                panic!(concat!(
+                    "nz::lit!: expected integer literal with type suffix, found `",
+                    stringify!($int_literal), "`"
+                ));
+            }
+            match core::num::NonZero::<_>::new($int_literal) {
+                Some(non_zero) => non_zero,
+                None => panic!(concat!(
+                    "nz::lit!: expected non-zero literal `", stringify!($int_literal), "`"
+                )),
+            }
+        }
+    };
+}
@@ -0,0 +1,77 @@
+//! Macros that help using [`NonZero`][`core::num::NonZero`] in generic code
+//! with `num-traits`.
+
+/// Generates a non-zero one macro for the specified integer type.
+macro_rules! gen_nz_one_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] of one, the multiplicative identity.")]
+        #[doc = r""]
+        #[doc = concat!("The expansion is [`nz::", stringify!($int_type), "!(1)`][`crate::", stringify!($int_type), "`], which is the non-zero")]
+        /// counterpart of `num_traits::One::one()` for contexts where a
+        /// constant is needed.
+        ///
+        /// Available on crate feature `num-traits` only.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const ONE: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!();")]
+        /// # assert_eq!(1, ONE.get());
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            () => {
+                $crate::$int_type!(1)
+            };
+        }
+    };
+}
+
+/// Generates a non-zero one macro from each macro name and integer type pair.
+macro_rules! gen_nz_one_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_one_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_one_macros!(
+    one_i8: i8,
+    one_i16: i16,
+    one_i32: i32,
+    one_i64: i64,
+    one_i128: i128,
+    one_isize: isize,
+    one_u8: u8,
+    one_u16: u16,
+    one_u32: u32,
+    one_u64: u64,
+    one_u128: u128,
+    one_usize: usize
+);
+
+/// Creates a [`NonZero`][`core::num::NonZero`] of one, the multiplicative
+/// identity, whose integer type is inferred from the context.
+///
+/// The expansion is [`nz::non_zero!(1)`][`crate::non_zero`], which is the
+/// non-zero counterpart of `num_traits::One::one()` for contexts where a
+/// constant is needed. Without any type information, the integer type falls
+/// back to [`prim@i32`].
+///
+/// Available on crate feature `num-traits` only.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::num::NonZero;
+/// const ONE: NonZero<u64> = nz::one!();
+/// let one: NonZero<i8> = nz::one!();
+/// # assert_eq!(1, ONE.get());
+/// # assert_eq!(1, one.get());
+/// ```
+#[macro_export]
+macro_rules! one {
+    () => {
+        $crate::non_zero!(1)
+    };
+}
@@ -0,0 +1,355 @@
+//! Macros that create a [`NonZero`][`core::num::NonZero`] by parsing strings
+//! at compile time.
+
+/// Parses a constant string of the given radix into a
+/// [`NonZero`][`core::num::NonZero`] of an unsigned integer type with panic
+/// messages that contain the name of the calling macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __nz_parse {
+    ($macro_name:ident, $int_type:ident, $src_expr:expr, $radix_expr:expr) => {
+        const {
+            let value =
+                match $crate::__private::parse_u128($src_expr, $radix_expr) {
+                    Ok(value) => value,
+                    Err($crate::__private::ParseError::InvalidRadix) => {
+                        panic!(concat!(
+                            "nz::",
+                            stringify!($macro_name),
+                            "!: radix must be in the range of `2..=36`"
+                        ))
+                    }
+                    Err($crate::__private::ParseError::Empty) => {
+                        panic!(concat!(
+                            "nz::",
+                            stringify!($macro_name),
+                            "!: cannot parse integer from empty string"
+                        ))
+                    }
+                    Err($crate::__private::ParseError::InvalidDigit) => {
+                        panic!(concat!(
+                            "nz::",
+                            stringify!($macro_name),
+                            "!: invalid digit found in string"
+                        ))
+                    }
+                    Err($crate::__private::ParseError::Overflow) => {
+                        panic!(concat!(
+                            "nz::",
+                            stringify!($macro_name),
+                            "!: number too large to fit in ",
+                            stringify!($int_type)
+                        ))
+                    }
+                };
+            if value > <$int_type>::MAX as u128 {
+                panic!(concat!(
+                    "nz::",
+                    stringify!($macro_name),
+                    "!: number too large to fit in ",
+                    stringify!($int_type)
+                ));
+            }
+            match core::num::NonZero::<$int_type>::new(value as $int_type) {
+                Some(non_zero) => non_zero,
+                None => panic!(concat!(
+                    "nz::",
+                    stringify!($macro_name),
+                    "!: expected non-zero ",
+                    stringify!($int_type),
+                    " value"
+                )),
+            }
+        }
+    };
+}
+
+/// Generates a non-zero string parsing macro with radix for the specified
+/// unsigned integer type.
+macro_rules! gen_nz_from_str_radix_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Parses a constant string of the given radix into a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`]")]
+        /// at compile time.
+        ///
+        #[doc = concat!("The string is parsed like [`", stringify!($int_type), "::from_str_radix`], so it may start")]
+        /// with a `+` sign and both lowercase and uppercase letters are accepted
+        /// as digits. Like in integer literals, `_` separators are skipped after
+        /// the first digit. The radix must be in the range of `2..=36`.
+        ///
+        /// If the radix is invalid, the string cannot be parsed, the number
+        /// does not fit into the integer type or it is zero, a [`panic`] will
+        /// occur at compile time with a message that describes the error.
+        ///
+        /// # Examples
+        ///
+        /// #### From string literal
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(\"7f\", 16);")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(\"+101\", 2);")]
+        #[doc = concat!(" let separated = nz::", stringify!($macro_name), "!(\"1_0\", 16);")]
+        /// # assert_eq!(0x7f, NZ.get());
+        /// # assert_eq!(0b101, nz.get());
+        /// # assert_eq!(0x10, separated.get());
+        /// ```
+        ///
+        /// #### From constant value
+        /// ```rust
+        /// const SRC: &str = "Z";
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(SRC, 36);")]
+        /// # assert_eq!(35, nz.get());
+        /// ```
+        ///
+        /// #### Invalid digit fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(\"12\", 2);")]
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(\"00\", 16);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($src_expr:expr, $radix_expr:expr) => {
+                $crate::__nz_parse!($macro_name, $int_type, $src_expr, $radix_expr)
+            };
+        }
+    };
+}
+
+/// Generates a non-zero string parsing macro with radix from each macro name
+/// and unsigned integer type pair.
+macro_rules! gen_nz_from_str_radix_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_from_str_radix_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_from_str_radix_macros!(
+    from_str_radix_u8: u8,
+    from_str_radix_u16: u16,
+    from_str_radix_u32: u32,
+    from_str_radix_u64: u64,
+    from_str_radix_u128: u128,
+    from_str_radix_usize: usize
+);
+
+/// Generates a non-zero decimal string parsing macro for the specified
+/// unsigned integer type.
+macro_rules! gen_nz_parse_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Parses a constant decimal string into a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] at compile time.")]
+        #[doc = r""]
+        #[doc = concat!("It is the same as [`nz::from_str_radix_", stringify!($int_type), "!`][`crate::from_str_radix_", stringify!($int_type), "`] with radix 10, so the string may")]
+        /// start with a `+` sign and `_` separators are skipped after the first
+        /// digit. If the string is empty, contains a non-digit character, the
+        /// number does not fit into the integer type or it is zero, a [`panic`]
+        /// will occur at compile time with a message that describes the error.
+        ///
+        /// # Examples
+        ///
+        /// #### From string literal
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(\"127\");")]
+        #[doc = concat!(" let nz = nz::", stringify!($macro_name), "!(\"+1_0\");")]
+        /// # assert_eq!(127, NZ.get());
+        /// # assert_eq!(10, nz.get());
+        /// ```
+        ///
+        /// #### Empty string fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(\"\");")]
+        /// ```
+        ///
+        /// #### Non-digit character fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(\"12a\");")]
+        /// ```
+        ///
+        /// #### Overflowing number fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(\"340282366920938463463374607431768211456\");")]
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(\"0\");")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($src_expr:expr) => {
+                $crate::__nz_parse!($macro_name, $int_type, $src_expr, 10)
+            };
+        }
+    };
+}
+
+/// Generates a non-zero decimal string parsing macro from each macro name and
+/// unsigned integer type pair.
+macro_rules! gen_nz_parse_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_parse_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_parse_macros!(
+    parse_u8: u8,
+    parse_u16: u16,
+    parse_u32: u32,
+    parse_u64: u64,
+    parse_u128: u128,
+    parse_usize: usize
+);
+
+/// Generates a non-zero environment variable macro for the specified unsigned
+/// integer type.
+macro_rules! gen_nz_env_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Parses the decimal value of an environment variable into a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`]")]
+        /// at compile time.
+        ///
+        /// The environment variable is read with [`env!`][`core::env`], so it
+        /// must be defined when the crate is compiled (e.g. set by a build
+        /// script with `cargo:rustc-env`). If the environment variable is not
+        /// defined, a compilation error is emitted. If its value cannot be
+        /// parsed, does not fit into the integer type or is zero, a [`panic`]
+        /// will occur at compile time with a message that describes the error.
+        ///
+        /// # Examples
+        ///
+        /// #### From environment variable
+        /// ```rust, ignore
+        /// # use std::num::NonZero;
+        /// // build.rs: println!("cargo:rustc-env=MAX_CONNS=64");
+        #[doc = concat!(" const MAX_CONNS: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(\"MAX_CONNS\");")]
+        /// ```
+        ///
+        /// #### Undefined environment variable fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(\"NZ_UNDEFINED_ENV_VAR\");")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($env_name:literal) => {
+                $crate::__nz_parse!(
+                    $macro_name,
+                    $int_type,
+                    core::env!(
+                        $env_name,
+                        concat!(
+                            "nz::", stringify!($macro_name), "!: environment variable `",
+                            $env_name, "` is not defined"
+                        )
+                    ),
+                    10
+                )
+            };
+        }
+    };
+}
+
+/// Generates a non-zero environment variable macro from each macro name and
+/// unsigned integer type pair.
+macro_rules! gen_nz_env_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_env_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_env_macros!(
+    env_u8: u8,
+    env_u16: u16,
+    env_u32: u32,
+    env_u64: u64,
+    env_u128: u128,
+    env_usize: usize
+);
+
+/// Generates a non-zero macro that takes the digits of an integer literal of
+/// the specified radix without prefix for the specified unsigned integer type.
+macro_rules! gen_nz_radix_digits_macro {
+    (
+        $macro_name:ident, $int_type:ident, $radix:literal, $radix_name:literal, $prefix:literal,
+        $example_digits:tt -> $example:literal, $str_digits:literal -> $str_example:literal,
+        $invalid_digits:tt
+    ) => {
+        #[doc = concat!("Creates a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] from the ", $radix_name, " digits of an integer literal")]
+        #[doc = concat!("without the `", $prefix, "` prefix at compile time.")]
+        #[doc = r""]
+        #[doc = concat!("The argument is a single token of digits (e.g. `", stringify!($example_digits), "`) or a string literal of them")]
+        #[doc = concat!("(e.g. `", stringify!($str_digits), "`), which is parsed in radix ", $radix, ". Like in integer literals, `_`")]
+        /// separators are skipped after the first digit. Digits that do not form
+        /// a valid token must be given as a string literal, e.g. hexadecimal
+        /// digits that start with a decimal digit followed by `e` or `E` (like
+        /// `1E`) are lexed as a malformed floating-point literal.
+        /// If the token contains an invalid digit, the number does not fit into
+        /// the integer type or it is zero, a [`panic`] will occur at compile
+        /// time with a message that describes the error.
+        ///
+        /// # Examples
+        ///
+        /// #### From digits
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(", stringify!($example_digits), ");")]
+        #[doc = concat!(" # assert_eq!(", $example, ", NZ.get());")]
+        /// ```
+        ///
+        /// #### From string literal
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const NZ: NonZero<", stringify!($int_type), "> = nz::", stringify!($macro_name), "!(", stringify!($str_digits), ");")]
+        #[doc = concat!(" # assert_eq!(", $str_example, ", NZ.get());")]
+        /// ```
+        ///
+        /// #### Invalid digit fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(", stringify!($invalid_digits), ");")]
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(00);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($digits:tt) => {
+                $crate::__nz_parse!(
+                    $macro_name,
+                    $int_type,
+                    $crate::__private::unquote(stringify!($digits)),
+                    $radix
+                )
+            };
+        }
+    };
+}
+
+/// Generates non-zero hexadecimal, octal and binary digits macros from each
+/// macro name triple and unsigned integer type.
+macro_rules! gen_nz_radix_digits_macros {
+    ($($hex_macro_name:ident, $oct_macro_name:ident, $bin_macro_name:ident: $int_type:ident), *) => {
+        $(
+            gen_nz_radix_digits_macro!(
+                $hex_macro_name, $int_type, 16, "hexadecimal", "0x", 7F -> "0x7f", "1E" -> "0x1e", 7G
+            );
+            gen_nz_radix_digits_macro!(
+                $oct_macro_name, $int_type, 8, "octal", "0o", 17 -> "0o17", "77" -> "0o77", 18
+            );
+            gen_nz_radix_digits_macro!(
+                $bin_macro_name, $int_type, 2, "binary", "0b", 10_10 -> "0b10_10", "1" -> "0b1", 1012
+            );
+        )*
+    };
+}
+
+gen_nz_radix_digits_macros!(
+    hex_u8, oct_u8, bin_u8: u8,
+    hex_u16, oct_u16, bin_u16: u16,
+    hex_u32, oct_u32, bin_u32: u32,
+    hex_u64, oct_u64, bin_u64: u64,
+    hex_u128, oct_u128, bin_u128: u128,
+    hex_usize, oct_usize, bin_usize: usize
+);
@@ -0,0 +1,24 @@
+//! Re-exports of every macro and function of the crate.
+//!
+//! Glob importing the module brings all of the macros into scope, so they can
+//! be used without the `nz::` prefix in files that create many non-zero values.
+//! The macros of the integer types are in the macro namespace, so importing
+//! them does not shadow the primitive types with the same name.
+//!
+//! Macros can be imported with `use` since the 2018 edition. On the 2015
+//! edition, use `#[macro_use] extern crate nz;` instead.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use nz::prelude::*;
+//! use std::num::NonZero;
+//!
+//! const NZ: NonZero<u8> = u8!(5);
+//! let value: u8 = NZ.get();
+//! let product = product_u32!(2, 3, 4);
+//! # assert_eq!(5, value);
+//! # assert_eq!(24, product.get());
+//! ```
+
+pub use crate::*;
@@ -0,0 +1,44 @@
+//! Macros that create ranges with a non-zero length.
+
+/// Creates a non-empty [`Range<usize>`][`core::ops::Range`] starting at zero
+/// together with its [`NonZero<usize>`][`core::num::NonZero`] length from a
+/// literal, a constant value or expression that evaluates to [`prim@usize`].
+///
+/// The result is a `(length, 0..length.get())` tuple, so the range can be
+/// iterated while the length is still known to be positive. The length is
+/// checked like in [`nz::usize!`][`crate::usize`], so if it evaluates to zero,
+/// a [`panic`] will occur at compile time.
+///
+/// # Examples
+///
+/// #### Loop bound
+/// ```rust
+/// # use std::num::NonZero;
+/// const LANES: usize = 4;
+/// let (len, range) = nz::range_len_usize!(LANES);
+/// let mut count = 0;
+/// for _ in range {
+///     count += 1;
+/// }
+/// # assert_eq!(LANES, len.get());
+/// # assert_eq!(len.get(), count);
+/// ```
+///
+/// #### Zero length fails to compile
+/// ```rust, compile_fail
+/// let _ = nz::range_len_usize!(0);
+/// ```
+#[macro_export]
+macro_rules! range_len_usize {
+    ($len_expr:expr) => {{
+        let len: core::num::NonZero<usize> = $crate::usize!(
+            $len_expr,
+            concat!(
+                "nz::range_len_usize!: expected non-zero length `",
+                stringify!($len_expr),
+                "`"
+            )
+        );
+        (len, 0..len.get())
+    }};
+}
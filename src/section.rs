@@ -0,0 +1,71 @@
+//! Macros that declare [`NonZero`][`core::num::NonZero`] statics placed in a
+//! specific link section.
+
+/// Generates a link section static macro for the specified integer type.
+macro_rules! gen_nz_section_macro {
+    ($macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Declares a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] static that is placed in a link section.")]
+        #[doc = r""]
+        /// The arguments are the name of the section, the name of the static,
+        /// which can be preceded by a visibility, and a literal, a constant
+        /// value or expression that is checked like in
+        #[doc = concat!("[`nz::", stringify!($int_type), "!`][`crate::", stringify!($int_type), "`]. The static has the `#[link_section]` and `#[used]`")]
+        /// attributes, so it is kept in the section even if it is not referred
+        /// to. If the value evaluates to zero, a [`panic`] will occur at compile
+        /// time.
+        ///
+        /// Available on crate feature `link-section` only, because the format of
+        /// section names depends on the target (e.g. Mach-O requires
+        /// `segment,section`).
+        ///
+        /// # Examples
+        ///
+        /// #### Static in link section
+        /// ```rust
+        /// # #[cfg(not(target_os = "linux"))] fn main() {}
+        /// # #[cfg(target_os = "linux")] fn main() {
+        #[doc = concat!(" nz::", stringify!($macro_name), "!(\".config\", pub BAUD_DIVISOR, 16);")]
+        /// # assert_eq!(16, BAUD_DIVISOR.get());
+        /// # }
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        /// # #[cfg(target_vendor = "apple")]
+        #[doc = concat!(" # nz::", stringify!($macro_name), "!(\"__DATA,__config\", BAUD_DIVISOR, 0);")]
+        /// # #[cfg(not(target_vendor = "apple"))]
+        #[doc = concat!(" nz::", stringify!($macro_name), "!(\".config\", BAUD_DIVISOR, 0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($section:literal, $vis:vis $static_name:ident, $int_expr:expr) => {
+                #[link_section = $section]
+                #[used]
+                $vis static $static_name: core::num::NonZero<$int_type> = $crate::$int_type!($int_expr);
+            };
+        }
+    };
+}
+
+/// Generates a link section static macro from each macro name and integer type
+/// pair.
+macro_rules! gen_nz_section_macros {
+    ($($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_section_macro!($macro_name, $int_type);)*
+    };
+}
+
+gen_nz_section_macros!(
+    section_i8: i8,
+    section_i16: i16,
+    section_i32: i32,
+    section_i64: i64,
+    section_i128: i128,
+    section_isize: isize,
+    section_u8: u8,
+    section_u16: u16,
+    section_u32: u32,
+    section_u64: u64,
+    section_u128: u128,
+    section_usize: usize
+);
@@ -0,0 +1,80 @@
+//! Macros that help using [`NonZero`][`core::num::NonZero`] constants with
+//! `serde`.
+
+/// Generates a `serde` default function macro for the specified integer type.
+macro_rules! gen_nz_serde_default_macro {
+    ($d:tt $macro_name:ident, $int_type:ident) => {
+        #[doc = concat!("Declares a constant function that returns a [`NonZero<", stringify!($int_type), ">`][`core::num::NonZero`] created from")]
+        #[doc = concat!("a literal, a constant value or expression that evaluates to [`prim@", stringify!($int_type), "`].")]
+        #[doc = r""]
+        /// The declared function can be used as the default value of a field
+        /// in `serde` (e.g. `#[serde(default = "default_capacity")]`). The
+        /// first argument is the name of the function, which can be preceded by
+        /// attributes and a visibility. The value is checked at compile time, so
+        /// if it evaluates to zero, a [`panic`] will occur at compile time.
+        ///
+        /// Available on crate feature `serde` only.
+        ///
+        /// # Examples
+        ///
+        /// #### Default value function
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" nz::", stringify!($macro_name), "!(pub(crate) default_capacity, 4);")]
+        ///
+        /// // #[derive(serde::Deserialize)]
+        /// struct Config {
+        ///     // #[serde(default = "default_capacity")]
+        #[doc = concat!("     capacity: NonZero<", stringify!($int_type), ">,")]
+        /// }
+        ///
+        /// let config = Config { capacity: default_capacity() };
+        /// # assert_eq!(4, config.capacity.get());
+        /// ```
+        ///
+        /// #### Zero default value fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" nz::", stringify!($macro_name), "!(default_capacity, 0);")]
+        /// # let _ = default_capacity();
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($d(#[$d attr:meta])* $d vis:vis $d fn_name:ident, $d int_expr:expr) => {
+                $d(#[$d attr])*
+                $d vis const fn $d fn_name() -> core::num::NonZero<$int_type> {
+                    $crate::$int_type!(
+                        $d int_expr,
+                        concat!(
+                            "nz::", stringify!($macro_name), "!: expected non-zero ",
+                            stringify!($int_type), " default value"
+                        )
+                    )
+                }
+            };
+        }
+    };
+}
+
+/// Generates a `serde` default function macro from each macro name and integer
+/// type pair.
+macro_rules! gen_nz_serde_default_macros {
+    ($d:tt $($macro_name:ident: $int_type:ident), *) => {
+        $(gen_nz_serde_default_macro!($d $macro_name, $int_type);)*
+    };
+}
+
+gen_nz_serde_default_macros!(
+    $
+    serde_default_i8: i8,
+    serde_default_i16: i16,
+    serde_default_i32: i32,
+    serde_default_i64: i64,
+    serde_default_i128: i128,
+    serde_default_isize: isize,
+    serde_default_u8: u8,
+    serde_default_u16: u16,
+    serde_default_u32: u32,
+    serde_default_u64: u64,
+    serde_default_u128: u128,
+    serde_default_usize: usize
+);
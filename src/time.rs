@@ -0,0 +1,56 @@
+//! Macros that create non-zero durations expressed in a specific time unit.
+
+/// Generates a non-zero duration macro for the specified time unit.
+macro_rules! gen_nz_time_macro {
+    ($macro_name:ident, $unit:literal) => {
+        #[doc = concat!("Creates a [`NonZero<u64>`][`core::num::NonZero`] number of ", $unit, " from a literal, a constant")]
+        /// value or expression that evaluates to [`prim@u64`].
+        ///
+        /// It is the same as [`nz::u64!`][`crate::u64`], but the name of the
+        /// macro documents the unit of the value and the panic message mentions
+        /// it. If the argument evaluates to zero, a [`panic`] will occur at
+        /// compile time.
+        ///
+        /// # Examples
+        ///
+        /// #### Constant duration
+        /// ```rust
+        /// # use std::num::NonZero;
+        #[doc = concat!(" const TIMEOUT: NonZero<u64> = nz::", stringify!($macro_name), "!(30);")]
+        #[doc = concat!(" let interval = nz::", stringify!($macro_name), "!(TIMEOUT.get() * 2);")]
+        /// # assert_eq!(30, TIMEOUT.get());
+        /// # assert_eq!(60, interval.get());
+        /// ```
+        ///
+        /// #### Zero fails to compile
+        /// ```rust, compile_fail
+        #[doc = concat!(" let _ = nz::", stringify!($macro_name), "!(0);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($int_expr:expr) => {
+                $crate::u64!(
+                    $int_expr,
+                    concat!(
+                        "nz::", stringify!($macro_name), "!: expected non-zero number of ",
+                        $unit
+                    )
+                )
+            };
+        }
+    };
+}
+
+/// Generates a non-zero duration macro from each macro name and time unit
+/// pair.
+macro_rules! gen_nz_time_macros {
+    ($($macro_name:ident: $unit:literal), *) => {
+        $(gen_nz_time_macro!($macro_name, $unit);)*
+    };
+}
+
+gen_nz_time_macros!(
+    secs: "seconds",
+    millis: "milliseconds",
+    micros: "microseconds"
+);